@@ -0,0 +1,72 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use clap::Parser;
+use cliclack::log;
+
+use crate::common::bench::{build_bench, write_bench_crate};
+use crate::common::fetch::{self, find_problem_file, missing_rust_problem_message};
+use crate::common::lang::Lang;
+
+/// The bench command
+/// This command generates a criterion benchmark for a problem from its sample inputs, plus any
+/// extra cases in cases/<problem>.txt (same format as the sample test case: one value per line,
+/// one line per parameter), and runs it. The bench is written to .quip/bench, outside of the
+/// tracked sources.
+#[derive(Parser, Debug)]
+pub struct BenchCommand {
+    /// The problem ID to benchmark
+    #[arg(short, long)]
+    id: u32,
+}
+
+impl BenchCommand {
+    pub async fn run(&self) {
+        let Some(file_path) = find_problem_file(self.id, Lang::Rust) else {
+            log::error(missing_rust_problem_message(self.id)).expect("Failed to log");
+            return;
+        };
+
+        let Some(problem) = fetch::get_problem(self.id, false).await else {
+            log::error(format!("Failed to get problem #{}.", self.id)).expect("Failed to log");
+            return;
+        };
+
+        let module = file_path.file_stem().unwrap().to_string_lossy();
+        let cases_path = Path::new("./cases").join(format!("{}.txt", module));
+        let cases = fs::read_to_string(&cases_path).unwrap_or_default();
+
+        let source = fs::read_to_string(&file_path).expect("Failed to read problem file");
+        let Some(bench) = build_bench(&problem, &file_path, &source, &cases) else {
+            log::error(format!(
+                "No benchmarkable cases for problem #{}, add some to {}.",
+                self.id,
+                cases_path.display()
+            ))
+            .expect("Failed to log");
+            return;
+        };
+
+        let manifest = match write_bench_crate(&bench) {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                log::error(format!("Failed to write the bench: {}", e)).expect("Failed to log");
+                return;
+            }
+        };
+
+        // Share the target directory so the dependencies aren't built twice.
+        let status = Command::new("cargo")
+            .arg("bench")
+            .arg("--manifest-path")
+            .arg(&manifest)
+            .args(["--target-dir", "target", "--bench", "problems"])
+            .status()
+            .expect("Failed to run cargo bench");
+
+        if !status.success() {
+            log::error("Benchmark failed.").expect("Failed to log");
+        }
+    }
+}
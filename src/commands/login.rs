@@ -0,0 +1,87 @@
+use std::io::{self, Read};
+
+use clap::Parser;
+use cliclack::{input, log, note, password, spinner};
+
+use crate::common::auth::store_cookie;
+use crate::common::fetch;
+
+/// The login command
+/// This command stores your LeetCode session cookie so that pull, submit and stats can act on
+/// your behalf. The cookie is validated before it's stored, and `LEETCODE_COOKIE` still
+/// overrides it when set.
+#[derive(Parser, Debug)]
+pub struct LoginCommand {
+    /// Read the full cookie header from stdin, instead of being prompted for LEETCODE_SESSION
+    /// and csrftoken
+    #[arg(long)]
+    stdin: bool,
+}
+
+impl LoginCommand {
+    pub async fn run(&self) {
+        let cookie = if self.stdin {
+            match read_cookie() {
+                Ok(cookie) => cookie,
+                Err(e) => {
+                    log::error(format!("Failed to read cookie from stdin: {}", e))
+                        .expect("Failed to log");
+                    return;
+                }
+            }
+        } else {
+            prompt_cookie()
+        };
+
+        let mut spinner = spinner();
+        spinner.start("Validating session...");
+        let status = match fetch::get_user_status(&cookie).await {
+            Ok(status) => status,
+            Err(e) => {
+                spinner.error(format!("Failed to reach LeetCode: {}", e));
+                return;
+            }
+        };
+
+        if !status.is_signed_in {
+            spinner.error("LeetCode did not accept this session, please copy the cookies again.");
+            return;
+        }
+        spinner.stop(format!("Signed in as {}.", status.username));
+
+        match store_cookie(&cookie) {
+            Ok(path) => log::success(format!("Session stored in {}", path.display())),
+            Err(e) => log::error(format!("Failed to store session: {}", e)),
+        }
+        .expect("Failed to log");
+    }
+}
+
+fn read_cookie() -> io::Result<String> {
+    let mut cookie = String::new();
+    io::stdin().read_to_string(&mut cookie)?;
+    Ok(cookie.trim().to_string())
+}
+
+fn prompt_cookie() -> String {
+    note(
+        "Getting your session",
+        "Log in to leetcode.com in your browser, open the developer tools and find the cookies\n\
+         of leetcode.com (Application > Cookies in Chrome, Storage > Cookies in Firefox).\n\
+         Copy the values of LEETCODE_SESSION and csrftoken.",
+    )
+    .expect("Failed to print note");
+
+    let session: String = password("LEETCODE_SESSION")
+        .interact()
+        .expect("Failed to get session");
+    let csrf_token: String = input("csrftoken")
+        .interact()
+        .expect("Failed to get csrf token");
+
+    format!(
+        "LEETCODE_SESSION={}; csrftoken={}",
+        session.trim(),
+        csrf_token.trim()
+    )
+}
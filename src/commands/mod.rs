@@ -1,2 +1,10 @@
+pub(crate) mod bench;
 pub(crate) mod init;
+pub(crate) mod login;
+pub(crate) mod move_problem;
 pub(crate) mod pull;
+pub(crate) mod regen;
+pub(crate) mod stats;
+pub(crate) mod submit;
+pub(crate) mod sync;
+pub(crate) mod test;
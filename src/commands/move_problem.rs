@@ -0,0 +1,53 @@
+use std::fs;
+use std::path::Path;
+
+use clap::Parser;
+use cliclack::log;
+
+use crate::common::fetch::{find_problem_file, missing_rust_problem_message};
+use crate::common::lang::Lang;
+use crate::common::modules::{add_module, remove_module};
+
+/// The move command
+/// This command promotes a solved problem from src/problem to src/solution, updating the module
+/// declarations of both directories.
+#[derive(Parser, Debug)]
+pub struct MoveCommand {
+    /// The problem ID to move
+    #[arg(short, long)]
+    id: u32,
+}
+
+impl MoveCommand {
+    pub fn run(&self) {
+        let problem_dir = Path::new("./src/problem");
+        let solution_dir = Path::new("./src/solution");
+
+        let Some(file_path) = find_problem_file(self.id, Lang::Rust) else {
+            log::error(missing_rust_problem_message(self.id)).expect("Failed to log");
+            return;
+        };
+
+        if file_path.parent() != Some(problem_dir) {
+            log::info(format!("Problem #{} is already in solution/.", self.id))
+                .expect("Failed to log");
+            return;
+        }
+
+        if let Err(e) = move_problem(&file_path, problem_dir, solution_dir) {
+            log::error(format!("Failed to move problem: {}", e)).expect("Failed to log");
+            return;
+        }
+        log::success(format!("Moved problem #{} to solution/.", self.id)).expect("Failed to log");
+    }
+}
+
+fn move_problem(file_path: &Path, from: &Path, to: &Path) -> anyhow::Result<()> {
+    let name = file_path.file_stem().unwrap().to_string_lossy();
+
+    fs::rename(file_path, to.join(file_path.file_name().unwrap()))?;
+    remove_module(from, &name)?;
+    add_module(to, &name)?;
+
+    Ok(())
+}
@@ -1,14 +1,17 @@
 // use std::thread::sleep;
 
 // use anyhow::anyhow;
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 // use cliclack::{input, intro, log, note, outro, select, spinner, Confirm};
 use cliclack::{input, log};
 // use dialoguer::Editor;
+use rand::seq::SliceRandom;
 use regex::Regex;
 
-use crate::common::deal::deal_problem;
-use crate::common::fetch::{self, get_initialized_problems, get_user_problems};
+use crate::common::deal::{deal_problem, template_path};
+use crate::common::fetch::{self, get_pulled_problems, get_user_problems};
+use crate::common::lang::Lang;
+use crate::common::problem::UserProblems;
 
 /// The initialization command
 /// This command cleans the problem directories and sets up a blank repository. Run this after
@@ -16,22 +19,106 @@ use crate::common::fetch::{self, get_initialized_problems, get_user_problems};
 #[derive(Parser, Debug)]
 pub struct PullCommand {
     /// The problem ID to fetch
-    #[arg(short, long)]
+    #[arg(short, long, conflicts_with_all = ["random", "difficulty", "tag", "daily"])]
     id: Option<u32>,
 
+    /// Pull a random problem that hasn't been initialized yet
+    #[arg(short, long, default_value = "false")]
+    random: bool,
+
+    /// Only pick from problems of this difficulty (implies --random)
+    #[arg(short, long, value_enum)]
+    difficulty: Option<DifficultyLevel>,
+
+    /// Only pick from problems with this tag slug, e.g. dynamic-programming (implies --random)
+    #[arg(short, long)]
+    tag: Option<String>,
+
+    /// Pull today's daily challenge
+    #[arg(long, default_value = "false", conflicts_with_all = ["random", "difficulty", "tag"])]
+    daily: bool,
+
+    /// The language to pull the problem in. Problems in languages other than Rust are written to
+    /// <lang>/problem and can be run directly, e.g. `python3 python3/problem/p0001_two_sum.py`
+    #[arg(short, long, value_enum, default_value_t = Lang::Rust)]
+    lang: Lang,
+
+    /// The template profile from templates/ to use [default: QUIP_TEMPLATE or "default"]
+    #[arg(long)]
+    template: Option<String>,
+
+    /// Refetch the problem list and statement instead of using the local cache
+    #[arg(long, default_value = "false")]
+    refresh: bool,
+
     /// Force override of existing problem
     #[arg(long, default_value = "false")]
     force: bool,
 }
 
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum DifficultyLevel {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl DifficultyLevel {
+    fn level(&self) -> u32 {
+        match self {
+            DifficultyLevel::Easy => 1,
+            DifficultyLevel::Medium => 2,
+            DifficultyLevel::Hard => 3,
+        }
+    }
+}
+
 impl PullCommand {
     pub async fn run(&self) {
-        let _problems = get_user_problems().await;
+        let template = self
+            .template
+            .clone()
+            .or_else(|| std::env::var("QUIP_TEMPLATE").ok())
+            .unwrap_or_else(|| "default".to_owned());
+        if !template_path(&template, self.lang).exists() {
+            log::error(format!(
+                "Template {} does not exist.",
+                template_path(&template, self.lang).display()
+            ))
+            .expect("Failed to log");
+            return;
+        }
 
-        let mut initialized = get_initialized_problems();
+        let problems = get_user_problems(self.refresh).await;
+
+        let mut initialized = match get_pulled_problems(self.lang) {
+            Ok(ids) => ids,
+            Err(e) => {
+                log::error(format!("Failed to list pulled problems: {}", e))
+                    .expect("Failed to log");
+                return;
+            }
+        };
 
         let id = match &self.id {
             Some(id) => *id,
+            None if self.daily => match fetch::get_daily_problem_id().await {
+                Ok(id) => id,
+                Err(e) => {
+                    log::error(format!("Failed to get the daily challenge: {}", e))
+                        .expect("Failed to log");
+                    return;
+                }
+            },
+            None if self.random || self.difficulty.is_some() || self.tag.is_some() => {
+                match self.pick_random(problems.as_ref(), &initialized).await {
+                    Some(id) => id,
+                    None => {
+                        log::error("No problem matches the given filters.").expect("Failed to log");
+                        return;
+                    }
+                }
+            }
             None => {
                 let pid: String = input("Enter a problem id!")
                     .placeholder("1")
@@ -51,27 +138,69 @@ impl PullCommand {
         };
 
         if initialized.contains(&id) {
-            println!("The problem you chose has already been initialized in problem/");
+            println!("The problem you chose has already been initialized in problem/ or solution/");
             return;
         }
 
         log::info(format!("Fetching problem #{}", id)).expect("Failed to log");
-        let problem = fetch::get_problem(id).await.unwrap_or_else(|| {
-            panic!(
-                "Error: failed to get problem #{}\
+        let problem = fetch::get_problem(id, self.refresh)
+            .await
+            .unwrap_or_else(|| {
+                panic!(
+                    "Error: failed to get problem #{}\
                 (The problem may be paid-only or may not exist).",
-                id
-            )
-        });
-        let code = problem.code_definition.iter().find(|&d| d.value == *"rust");
+                    id
+                )
+            });
+        let code = problem
+            .code_definition
+            .iter()
+            .find(|&d| d.value == self.lang.slug());
         if code.is_none() {
-            println!("Problem {} has no rust version.", &id);
+            println!("Problem {} has no {} version.", &id, self.lang.slug());
             initialized.push(problem.question_id);
             return;
         }
 
         let code = code.unwrap();
 
-        deal_problem(&problem, code, true);
+        deal_problem(&problem, code, &template, self.lang, true);
+    }
+
+    // Pick a free, not yet initialized problem matching the difficulty and tag filters.
+    async fn pick_random(
+        &self,
+        problems: Option<&UserProblems>,
+        initialized: &[u32],
+    ) -> Option<u32> {
+        let tagged = match &self.tag {
+            Some(tag) => match fetch::get_tagged_problem_ids(tag).await {
+                Ok(ids) => Some(ids),
+                Err(e) => {
+                    log::error(format!("Failed to get problems tagged {}: {}", tag, e))
+                        .expect("Failed to log");
+                    return None;
+                }
+            },
+            None => None,
+        };
+
+        let candidates = problems?
+            .stat_status_pairs
+            .iter()
+            .filter(|p| !p.paid_only && !initialized.contains(&p.stat.frontend_question_id))
+            .filter(|p| {
+                self.difficulty
+                    .is_none_or(|d| p.difficulty.level == d.level())
+            })
+            .filter(|p| {
+                tagged
+                    .as_ref()
+                    .is_none_or(|ids| ids.contains(&p.stat.frontend_question_id))
+            })
+            .map(|p| p.stat.frontend_question_id)
+            .collect::<Vec<_>>();
+
+        candidates.choose(&mut rand::thread_rng()).copied()
     }
 }
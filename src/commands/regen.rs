@@ -0,0 +1,25 @@
+use std::path::Path;
+
+use clap::Parser;
+use cliclack::log;
+
+use crate::common::modules::regenerate_modules;
+
+/// The regen command
+/// This command rewrites the mod.rs files of src/problem and src/solution from the problem files
+/// that are actually on disk, e.g. after moving or deleting files by hand.
+#[derive(Parser, Debug)]
+pub struct RegenCommand {}
+
+impl RegenCommand {
+    pub fn run(&self) {
+        for dir in ["./src/problem", "./src/solution"] {
+            if let Err(e) = regenerate_modules(Path::new(dir)) {
+                log::error(format!("Failed to regenerate {}/mod.rs: {}", dir, e))
+                    .expect("Failed to log");
+                return;
+            }
+        }
+        log::success("Module declarations regenerated.").expect("Failed to log");
+    }
+}
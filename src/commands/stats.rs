@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use clap::Parser;
+use cliclack::{log, note};
+
+use crate::common::cache;
+use crate::common::fetch::{get_declared_problems, get_user_problems};
+use crate::common::problem::{Difficulty, StatWithStatus, UserProblems};
+
+// How many tags to list in the local summary.
+const TOP_TAGS: usize = 10;
+
+/// The stats command
+/// This command summarizes your progress: problems solved (in src/solution) and in progress (in
+/// src/problem) by difficulty and tag, and optionally your LeetCode account's statistics.
+#[derive(Parser, Debug)]
+pub struct StatsCommand {
+    /// Also show the statistics of your LeetCode account (needs `quip login` or LEETCODE_COOKIE)
+    #[arg(short, long, default_value = "false")]
+    remote: bool,
+}
+
+impl StatsCommand {
+    pub async fn run(&self) {
+        // The account statistics are part of the problem list, so refresh it when we need them.
+        let Some(problems) = get_user_problems(self.remote).await else {
+            log::error("Failed to get the problem list.").expect("Failed to log");
+            return;
+        };
+
+        let stats = problems
+            .stat_status_pairs
+            .iter()
+            .map(|p| (p.stat.frontend_question_id, p))
+            .collect::<HashMap<_, _>>();
+
+        let declared = |dir: &str| match get_declared_problems(Path::new(dir)) {
+            Ok(ids) => Some(
+                ids.iter()
+                    .filter_map(|id| stats.get(id).copied())
+                    .collect::<Vec<_>>(),
+            ),
+            Err(e) => {
+                log::error(format!("Failed to list problems: {}", e)).expect("Failed to log");
+                None
+            }
+        };
+        let (Some(solved), Some(in_progress)) =
+            (declared("./src/solution"), declared("./src/problem"))
+        else {
+            return;
+        };
+
+        note("Local progress", local_summary(&solved, &in_progress)).expect("Failed to print note");
+
+        if self.remote {
+            note("LeetCode progress", remote_summary(&problems)).expect("Failed to print note");
+        }
+    }
+}
+
+fn local_summary(solved: &[&StatWithStatus], in_progress: &[&StatWithStatus]) -> String {
+    let count = |problems: &[&StatWithStatus], level: u32| {
+        problems
+            .iter()
+            .filter(|p| p.difficulty.level == level)
+            .count()
+            .to_string()
+    };
+
+    let mut rows = (1..=3)
+        .map(|level| {
+            vec![
+                difficulty_name(level),
+                count(solved, level),
+                count(in_progress, level),
+            ]
+        })
+        .collect::<Vec<_>>();
+    rows.push(vec![
+        "Total".to_owned(),
+        solved.len().to_string(),
+        in_progress.len().to_string(),
+    ]);
+    let mut summary = table(&["Difficulty", "Solved", "In progress"], &rows);
+
+    // Tags are only known for problems whose statement is in the cache.
+    let mut tags = HashMap::new();
+    for problem in solved {
+        let Some(slug) = &problem.stat.question_title_slug else {
+            continue;
+        };
+        if let Some(question) = cache::load_question(slug) {
+            for tag in question.topic_tags {
+                *tags.entry(tag.name).or_insert(0) += 1;
+            }
+        }
+    }
+
+    if !tags.is_empty() {
+        let mut tags = tags.into_iter().collect::<Vec<_>>();
+        tags.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        let rows = tags
+            .into_iter()
+            .take(TOP_TAGS)
+            .map(|(name, count)| vec![name, count.to_string()])
+            .collect::<Vec<_>>();
+        summary.push_str("\n\n");
+        summary.push_str(&table(&["Tag", "Solved"], &rows));
+    }
+
+    summary
+}
+
+fn remote_summary(problems: &UserProblems) -> String {
+    let total = |level: u32| {
+        problems
+            .stat_status_pairs
+            .iter()
+            .filter(|p| p.difficulty.level == level)
+            .count()
+    };
+
+    let rows = [
+        (1, problems.ac_easy),
+        (2, problems.ac_medium),
+        (3, problems.ac_hard),
+    ]
+    .into_iter()
+    .map(|(level, accepted)| {
+        vec![
+            difficulty_name(level),
+            format!("{} / {}", accepted, total(level)),
+        ]
+    })
+    .chain([vec![
+        "Total".to_owned(),
+        format!("{} / {}", problems.num_solved, problems.num_total),
+    ]])
+    .collect::<Vec<_>>();
+
+    format!(
+        "User: {}\n\n{}",
+        problems.user_name,
+        table(&["Difficulty", "Accepted"], &rows)
+    )
+}
+
+fn difficulty_name(level: u32) -> String {
+    Difficulty { level }.to_string()
+}
+
+// Left-aligned columns separated by two spaces, with a rule under the header.
+fn table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let widths = (0..headers.len())
+        .map(|i| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .chain([headers[i].len()])
+                .max()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let line = |cells: &[&str]| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_owned()
+    };
+
+    let mut lines = vec![
+        line(headers),
+        widths
+            .iter()
+            .map(|w| "-".repeat(*w))
+            .collect::<Vec<_>>()
+            .join("  "),
+    ];
+    for row in rows {
+        lines.push(line(&row.iter().map(String::as_str).collect::<Vec<_>>()));
+    }
+    lines.join("\n")
+}
@@ -0,0 +1,249 @@
+use std::fs;
+use std::time::Duration;
+
+use clap::Parser;
+use cliclack::{log, note, spinner};
+
+use crate::common::fetch::{self, find_problem_file, missing_rust_problem_message};
+use crate::common::lang::Lang;
+use crate::common::problem::SubmissionCheck;
+
+// How many times to poll the judge before giving up, one second apart.
+const JUDGE_POLL_ATTEMPTS: u32 = 30;
+
+/// The submit command
+/// This command reads a solved problem file, strips the generated scaffolding and submits the
+/// solution to LeetCode, then waits for the judge to report back.
+#[derive(Parser, Debug)]
+pub struct SubmitCommand {
+    /// The problem ID to submit
+    #[arg(short, long)]
+    id: u32,
+}
+
+impl SubmitCommand {
+    pub async fn run(&self) {
+        let Some(file_path) = find_problem_file(self.id, Lang::Rust) else {
+            log::error(missing_rust_problem_message(self.id)).expect("Failed to log");
+            return;
+        };
+
+        let source = fs::read_to_string(&file_path).expect("Failed to read problem file");
+        let code = strip_scaffolding(&source);
+
+        let Some(problem) = fetch::get_problem_stat(self.id).await else {
+            log::error(format!("Problem #{} does not exist.", self.id)).expect("Failed to log");
+            return;
+        };
+
+        let mut submitting = spinner();
+        submitting.start(format!("Submitting {}...", file_path.display()));
+        let submission_id = match fetch::submit_solution(&problem, &code).await {
+            Ok(id) => id,
+            Err(e) => {
+                submitting.error(format!("Failed to submit solution: {}", e));
+                return;
+            }
+        };
+        submitting.stop(format!("Submitted as #{}.", submission_id));
+
+        let mut judging = spinner();
+        judging.start("Waiting for the judge...");
+        for _ in 0..JUDGE_POLL_ATTEMPTS {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+
+            match fetch::check_submission(submission_id).await {
+                Ok(check) if check.state == "SUCCESS" => {
+                    judging.stop("Judged.");
+                    report(&check);
+                    return;
+                }
+                Ok(_) => continue,
+                Err(e) => {
+                    judging.error(format!("Failed to check submission: {}", e));
+                    return;
+                }
+            }
+        }
+        judging.error("Timed out waiting for the judge.");
+    }
+}
+
+fn report(check: &SubmissionCheck) {
+    let status = check.status_msg.as_deref().unwrap_or("Unknown");
+
+    if status == "Accepted" {
+        log::success(format!(
+            "Accepted: runtime {} (beats {:.2}%), memory {} (beats {:.2}%)",
+            check.status_runtime.as_deref().unwrap_or("-"),
+            check.runtime_percentile.unwrap_or_default(),
+            check.status_memory.as_deref().unwrap_or("-"),
+            check.memory_percentile.unwrap_or_default(),
+        ))
+        .expect("Failed to log");
+        return;
+    }
+
+    if let Some(error) = check
+        .compile_error
+        .as_ref()
+        .or(check.runtime_error.as_ref())
+    {
+        log::error(format!("{}:\n{}", status, error)).expect("Failed to log");
+        return;
+    }
+
+    log::error(format!(
+        "{}: {}/{} test cases passed",
+        status,
+        check.total_correct.unwrap_or_default(),
+        check.total_testcases.unwrap_or_default(),
+    ))
+    .expect("Failed to log");
+
+    if let Some(input) = &check.last_testcase {
+        note(
+            "Last test case",
+            format!(
+                "Input: {}\nExpected: {}\nOutput: {}",
+                input,
+                check.expected_output.as_deref().unwrap_or("-"),
+                check.code_output.as_deref().unwrap_or("-"),
+            ),
+        )
+        .expect("Failed to print note");
+    }
+}
+
+// Reduce a generated problem file to what LeetCode expects: drop the description header (a
+// `/** */` block, or the `//` lines of the min template), the `Solution` stub, crate-local
+// imports, the link comments and the test module.
+fn strip_scaffolding(source: &str) -> String {
+    let source = source.split("#[cfg(test)]").next().unwrap_or(source);
+    let source = match source.find("*/") {
+        Some(end) if source.trim_start().starts_with("/**") => &source[end + 2..],
+        _ => source,
+    };
+
+    source
+        .lines()
+        .skip_while(|line| {
+            let line = line.trim();
+            line.is_empty() || line.starts_with("//")
+        })
+        .filter(|line| {
+            let line = line.trim();
+            !(line == "pub struct Solution {}"
+                || line.starts_with("use crate::")
+                || line.starts_with("// problem:")
+                || line.starts_with("// discuss:"))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOLUTION: &str = "impl Solution {
+    pub fn two_sum(nums: Vec<i32>, target: i32) -> Vec<i32> {
+        vec![]
+    }
+}";
+
+    #[test]
+    fn test_strip_default_template() {
+        let source = format!(
+            "/**
+ * [1] Two Sum
+ *
+ * Given an array of integers <code>nums</code>, return indices of the two numbers.
+ */
+
+use crate::util::linked_list::{{to_list, ListNode}};
+
+pub struct Solution {{}}
+
+// problem: https://leetcode.com/problems/two-sum/
+// discuss: https://leetcode.com/problems/two-sum/discuss/?currentPage=1&orderBy=most_votes&query=
+
+{}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    #[test]
+    fn test_1() {{
+        assert_eq!(Solution::two_sum(vec![2, 7, 11, 15], 9), vec![0, 1]);
+    }}
+}}
+",
+            SOLUTION
+        );
+        assert_eq!(strip_scaffolding(&source), SOLUTION);
+    }
+
+    #[test]
+    fn test_strip_min_template() {
+        let source = format!(
+            "// [1] Two Sum (Easy)
+// https://leetcode.com/problems/two-sum/
+
+
+pub struct Solution {{}}
+
+{}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+}}
+",
+            SOLUTION
+        );
+        assert_eq!(strip_scaffolding(&source), SOLUTION);
+    }
+
+    #[test]
+    fn test_strip_design_problem() {
+        let code = "struct MinStack {}
+
+impl MinStack {
+    fn new() -> Self {
+        MinStack {}
+    }
+
+    fn push(&mut self, val: i32) {}
+}
+
+/**
+ * Your MinStack object will be instantiated and called as such:
+ * let obj = MinStack::new();
+ * obj.push(val);
+ */";
+        let source = format!(
+            "/**
+ * [155] Min Stack
+ *
+ * Remove <code>/ * block * /</code> comments, then design a stack.
+ */
+
+pub struct Solution {{}}
+
+// problem: https://leetcode.com/problems/min-stack/
+// discuss: https://leetcode.com/problems/min-stack/discuss/?currentPage=1&orderBy=most_votes&query=
+
+{}
+
+#[cfg(test)]
+mod tests {{}}
+",
+            code
+        );
+        assert_eq!(strip_scaffolding(&source), code);
+    }
+}
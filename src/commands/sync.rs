@@ -0,0 +1,69 @@
+use clap::Parser;
+use cliclack::{log, spinner};
+use indicatif::{ProgressBar, ProgressStyle};
+
+use crate::common::cache;
+use crate::common::fetch::{self, refresh_user_problems};
+
+/// The sync command
+/// This command refreshes the local problem cache so that `pull` works without network access.
+/// Statements are only cached on pull unless `--statements` is given.
+#[derive(Parser, Debug)]
+pub struct SyncCommand {
+    /// Also cache the statement of every free problem
+    #[arg(short, long, default_value = "false")]
+    statements: bool,
+}
+
+impl SyncCommand {
+    pub async fn run(&self) {
+        let mut spinner = spinner();
+        spinner.start("Fetching problem list...");
+        let problems = match refresh_user_problems().await {
+            Ok(problems) => problems,
+            Err(e) => {
+                spinner.error(format!("Failed to fetch problem list: {}", e));
+                return;
+            }
+        };
+        spinner.stop(format!(
+            "Cached {} problems.",
+            problems.stat_status_pairs.len()
+        ));
+
+        if !self.statements {
+            return;
+        }
+
+        let missing = problems
+            .stat_status_pairs
+            .iter()
+            .filter(|p| !p.paid_only)
+            .filter_map(|p| p.stat.question_title_slug.as_deref())
+            .filter(|slug| cache::load_question(slug).is_none())
+            .collect::<Vec<_>>();
+
+        let progress = ProgressBar::new(missing.len() as u64);
+        progress.set_style(ProgressStyle::with_template("{bar:40} {pos}/{len} {msg}").unwrap());
+
+        let mut failed = 0;
+        for slug in missing {
+            progress.set_message(slug.to_owned());
+            let cached = match fetch::get_question_request(slug).await {
+                Ok(question) => cache::store_question(slug, &question).is_ok(),
+                Err(_) => false,
+            };
+            if !cached {
+                failed += 1;
+            }
+            progress.inc(1);
+        }
+        progress.finish_and_clear();
+
+        if failed > 0 {
+            log::warning(format!("Failed to cache {} statements.", failed)).expect("Failed to log");
+        } else {
+            log::success("All statements cached.").expect("Failed to log");
+        }
+    }
+}
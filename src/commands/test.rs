@@ -0,0 +1,47 @@
+use std::process::Command;
+
+use clap::Parser;
+use cliclack::log;
+
+use crate::common::fetch::{find_problem_file, missing_rust_problem_message};
+use crate::common::lang::Lang;
+
+/// The test command
+/// This command runs the generated tests of a single problem, whether it still lives in
+/// src/problem or has been moved to src/solution.
+#[derive(Parser, Debug)]
+pub struct TestCommand {
+    /// The problem ID to test
+    #[arg(short, long)]
+    id: u32,
+}
+
+impl TestCommand {
+    pub fn run(&self) {
+        let Some(file_path) = find_problem_file(self.id, Lang::Rust) else {
+            log::error(missing_rust_problem_message(self.id)).expect("Failed to log");
+            return;
+        };
+
+        // src/<dir>/<file>.rs is the module <dir>::<file>, which is all cargo needs to filter on.
+        let module = file_path.file_stem().unwrap().to_string_lossy();
+        let dir = file_path
+            .parent()
+            .and_then(|p| p.file_name())
+            .unwrap()
+            .to_string_lossy();
+        let filter = format!("{}::{}::", dir, module);
+
+        log::info(format!("Running tests for {}", filter)).expect("Failed to log");
+        let status = Command::new("cargo")
+            .args(["test", "--lib", &filter])
+            .status()
+            .expect("Failed to run cargo test");
+
+        if status.success() {
+            log::success("All tests passed.").expect("Failed to log");
+        } else {
+            log::error("Some tests failed.").expect("Failed to log");
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+use anyhow::Context;
+
+// The session cookie is kept in the user's config dir, readable by the user only.
+fn cookie_path() -> Option<PathBuf> {
+    Some(dirs::config_dir()?.join("quipcode").join("cookie"))
+}
+
+/// The LeetCode session cookie. `LEETCODE_COOKIE` takes precedence over the one stored by
+/// `quip login`.
+pub fn load_cookie() -> Option<String> {
+    if let Ok(cookie) = std::env::var("LEETCODE_COOKIE") {
+        return Some(cookie);
+    }
+
+    let cookie = fs::read_to_string(cookie_path()?).ok()?;
+    Some(cookie.trim().to_owned())
+}
+
+/// Store the session cookie for later runs and return where it was written.
+pub fn store_cookie(cookie: &str) -> anyhow::Result<PathBuf> {
+    let path = cookie_path().context("Could not determine the config directory")?;
+    fs::create_dir_all(path.parent().unwrap())?;
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    let mut file = options.open(&path)?;
+    // The mode only applies to new files, so tighten an existing one before writing to it.
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(fs::Permissions::from_mode(0o600))?;
+    }
+    file.write_all(cookie.as_bytes())?;
+    Ok(path)
+}
@@ -0,0 +1,127 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::deal::{parse_test_inputs, solution_fn_name};
+use super::problem::Problem;
+use super::CACHE_DIR;
+
+// The bench is a crate of its own in the cache directory, so running it never touches tracked
+// files. It depends on this crate by path for `util`.
+const BENCH_MANIFEST: &str = r#"# Generated by `quip bench`.
+
+[package]
+name = "quip-bench"
+version = "0.0.0"
+edition = "2021"
+publish = false
+
+[workspace]
+
+[dependencies]
+quip = { path = "../.." }
+
+[dev-dependencies]
+criterion = "0.5.1"
+
+[[bench]]
+name = "problems"
+harness = false
+"#;
+
+/// Write the bench crate with `bench` as its only bench and return the path of its manifest.
+pub fn write_bench_crate(bench: &str) -> anyhow::Result<PathBuf> {
+    let dir = Path::new(CACHE_DIR).join("bench");
+    fs::create_dir_all(dir.join("benches"))?;
+
+    let manifest = dir.join("Cargo.toml");
+    fs::write(&manifest, BENCH_MANIFEST)?;
+    fs::write(dir.join("benches").join("problems.rs"), bench)?;
+
+    // Start from this crate's lock file so the bench builds against the same dependencies.
+    let lock = dir.join("Cargo.lock");
+    if !lock.exists() && Path::new("Cargo.lock").exists() {
+        fs::copy("Cargo.lock", lock)?;
+    }
+    Ok(manifest)
+}
+
+/// Build the source of a criterion bench that runs `Solution`'s method on every sample input
+/// and every extra case in `cases`, which uses the sample test case format.
+///
+/// The problem file is included with `#[path]`, so it's compiled as part of the bench and
+/// `crate::util` has to resolve to the library's `util`. `file_path` is relative to the root of
+/// the repository. Returns `None` when `Solution` has no method or there are no inputs to run.
+pub fn build_bench(
+    problem: &Problem,
+    file_path: &Path,
+    source: &str,
+    cases: &str,
+) -> Option<String> {
+    let fn_name = solution_fn_name(source)?;
+
+    let params = &problem.meta_data.params;
+    let inputs = parse_test_inputs(&problem.example_test_cases, params)
+        .into_iter()
+        .chain(parse_test_inputs(cases, params))
+        .flatten()
+        .collect::<Vec<_>>();
+    if inputs.is_empty() {
+        return None;
+    }
+
+    let mut benches = String::new();
+    for (i, args) in inputs.iter().enumerate() {
+        let fields = (0..args.len())
+            .map(|n| format!("args.{}", n))
+            .collect::<Vec<_>>();
+        benches.push_str(&format!(
+            r#"
+    c.bench_function("p{:04} case {}", |b| {{
+        b.iter_batched(
+            || ({},),
+            |args| Solution::{}({}),
+            BatchSize::SmallInput,
+        )
+    }});
+"#,
+            problem.question_id,
+            i + 1,
+            args.join(", "),
+            fn_name,
+            fields.join(", "),
+        ));
+    }
+
+    let inputs = inputs.concat().join(" ");
+    let mut extra_use = String::new();
+    if inputs.contains("to_list(") {
+        extra_use.push_str("use quip::util::linked_list::to_list;\n");
+    }
+    if inputs.contains("to_tree(") {
+        extra_use.push_str("use quip::util::tree::to_tree;\n");
+    }
+
+    Some(format!(
+        r#"// Generated by `quip bench --id {}`, overwritten on every run.
+
+use criterion::{{criterion_group, criterion_main, BatchSize, Criterion}};
+#[allow(unused_imports)]
+use quip::util;
+{}
+#[path = "../../../{}"]
+#[allow(dead_code, unused_imports)]
+mod problem;
+
+use problem::Solution;
+
+fn bench(c: &mut Criterion) {{{}}}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
+"#,
+        problem.question_id,
+        extra_use,
+        file_path.strip_prefix("./").unwrap_or(file_path).display(),
+        benches,
+    ))
+}
@@ -0,0 +1,43 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::problem::{Question, UserProblems};
+use super::CACHE_DIR;
+
+// The cache keeps the raw problem list and one file per question statement:
+//
+//   .quip/problems.json
+//   .quip/questions/<title-slug>.json
+
+fn problems_path() -> PathBuf {
+    Path::new(CACHE_DIR).join("problems.json")
+}
+
+fn question_path(title_slug: &str) -> PathBuf {
+    Path::new(CACHE_DIR)
+        .join("questions")
+        .join(format!("{}.json", title_slug))
+}
+
+pub fn load_problems() -> Option<UserProblems> {
+    let content = fs::read_to_string(problems_path()).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+pub fn store_problems(body: &str) -> anyhow::Result<()> {
+    fs::create_dir_all(CACHE_DIR)?;
+    fs::write(problems_path(), body)?;
+    Ok(())
+}
+
+pub fn load_question(title_slug: &str) -> Option<Question> {
+    let content = fs::read_to_string(question_path(title_slug)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+pub fn store_question(title_slug: &str, question: &Question) -> anyhow::Result<()> {
+    let path = question_path(title_slug);
+    fs::create_dir_all(path.parent().unwrap())?;
+    fs::write(path, serde_json::to_string(question)?)?;
+    Ok(())
+}
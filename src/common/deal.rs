@@ -1,39 +1,89 @@
+use chrono::Local;
 use regex::Regex;
-use std::path::Path;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
 use std::{fs, io::Write};
 
-use syn::{parse_file, ImplItem, Item, ItemFn, ReturnType, Type};
+use syn::{
+    parse_file, parse_str, Block, Expr, Fields, GenericArgument, ImplItem, Item, PathArguments,
+    ReturnType, Stmt, Type,
+};
 
-use super::problem::{CodeDefinition, Problem};
+use super::html::html_to_doc;
+use super::lang::Lang;
+use super::modules::add_module;
+use super::problem::{CodeDefinition, Param, Problem};
 
-pub fn deal_problem(problem: &Problem, code: &CodeDefinition, write_mod_file: bool) {
+/// Path of the template profile `name` for `lang`, e.g. `templates/default.rs`.
+pub fn template_path(name: &str, lang: Lang) -> PathBuf {
+    Path::new("./templates").join(format!("{}.{}", name, lang.extension()))
+}
+
+/// Write the problem file in `lang` from the template profile `template`. Templates can use:
+///
+/// - `__PROBLEM_ID__`, `__PROBLEM_TITLE__`, `__PROBLEM_DESC__`, `__PROBLEM_DIFFICULTY__`,
+///   `__PROBLEM_TAGS__`
+/// - `__PROBLEM_LINK__`, `__DISCUSS_LINK__`
+/// - `__EXTRA_USE__` (`use` lines for Rust, `#include` lines for C++), `__PROBLEM_DEFAULT_CODE__`,
+///   `__PROBLEM_TEST_CASES__`
+/// - `__PROBLEM_DATE__` (today) and `__PROBLEM_AUTHOR__` (`QUIP_AUTHOR`, or git's user.name)
+pub fn deal_problem(
+    problem: &Problem,
+    code: &CodeDefinition,
+    template: &str,
+    lang: Lang,
+    write_mod_file: bool,
+) {
     let file_name = format!(
         "p{:04}_{}",
         problem.question_id,
         problem.title_slug.replace('-', "_")
     );
 
-    let file_path = Path::new("./src/problem").join(format!("{}.rs", file_name));
+    let file_path = lang
+        .problem_dir()
+        .join(format!("{}.{}", file_name, lang.extension()));
     if file_path.exists() {
         println!("Problem {} already exists", file_name);
         return;
     }
+    fs::create_dir_all(lang.problem_dir()).unwrap();
 
-    let fixed_code = insert_return_type(&code.default_code);
-    // println!("{}", res);
+    let default_code = match lang {
+        Lang::Rust => insert_default_returns(&code.default_code),
+        Lang::Python3 => insert_python_pass(&code.default_code),
+        Lang::Cpp => insert_cpp_returns(&uncomment_cpp_definitions(&code.default_code)),
+    };
 
-    let template = fs::read_to_string("./template.rs").unwrap();
+    let template = fs::read_to_string(template_path(template, lang)).unwrap();
     let source = template
         .replace("__PROBLEM_TITLE__", &problem.title)
-        .replace("__PROBLEM_DESC__", &build_desc(&problem.content))
+        .replace("__PROBLEM_DESC__", &build_desc(&problem.content, lang))
+        .replace("__PROBLEM_DEFAULT_CODE__", &default_code)
+        .replace("__PROBLEM_ID__", &format!("{}", problem.question_id))
+        .replace("__PROBLEM_DIFFICULTY__", &problem.difficulty)
+        .replace("__PROBLEM_TAGS__", &parse_tags(problem))
         .replace(
-            "__PROBLEM_DEFAULT_CODE__",
-            &insert_return_in_code(&problem.return_type, &code.default_code),
+            "__PROBLEM_DATE__",
+            &Local::now().format("%Y-%m-%d").to_string(),
+        )
+        .replace("__PROBLEM_AUTHOR__", &parse_author())
+        .replace(
+            "__EXTRA_USE__",
+            &match lang {
+                Lang::Rust => parse_extra_use(&code.default_code),
+                Lang::Python3 => String::new(),
+                Lang::Cpp => parse_cpp_includes(&code.default_code),
+            },
         )
-        .replace("__PROBLEM_ID__", &format!("{}", problem.question_id))
-        .replace("__EXTRA_USE__", &parse_extra_use(&code.default_code))
         .replace("__PROBLEM_LINK__", &parse_problem_link(problem))
-        .replace("__DISCUSS_LINK__", &parse_discuss_link(problem));
+        .replace("__DISCUSS_LINK__", &parse_discuss_link(problem))
+        .replace(
+            "__PROBLEM_TEST_CASES__",
+            &build_test_cases(problem, &code.default_code, lang),
+        );
 
     let mut file = fs::OpenOptions::new()
         .write(true)
@@ -45,12 +95,8 @@ pub fn deal_problem(problem: &Problem, code: &CodeDefinition, write_mod_file: bo
     file.write_all(source.as_bytes()).unwrap();
     drop(file);
 
-    if write_mod_file {
-        let mut lib_file = fs::OpenOptions::new()
-            .append(true)
-            .open("./src/problem/mod.rs")
-            .unwrap();
-        writeln!(lib_file, "\nmod {};\n", file_name).unwrap();
+    if write_mod_file && lang == Lang::Rust {
+        add_module(&lang.problem_dir(), &file_name).unwrap();
     }
 }
 
@@ -69,6 +115,58 @@ fn parse_extra_use(code: &str) -> String {
     extra_use_line
 }
 
+// The headers of the standard library types used in the signatures.
+fn parse_cpp_includes(code: &str) -> String {
+    let headers = [
+        ("vector", "vector"),
+        ("string", "string"),
+        ("pair", "utility"),
+        ("map", "map"),
+        ("unordered_map", "unordered_map"),
+        ("set", "set"),
+        ("unordered_set", "unordered_set"),
+        ("queue", "queue"),
+        ("priority_queue", "queue"),
+        ("stack", "stack"),
+        ("deque", "deque"),
+    ];
+
+    let mut includes = headers
+        .iter()
+        .filter(|(ty, _)| Regex::new(&format!(r"\b{}\b", ty)).unwrap().is_match(code))
+        .map(|(_, header)| *header)
+        .collect::<Vec<_>>();
+    includes.sort();
+    includes.dedup();
+    includes
+        .iter()
+        .map(|header| format!("\n#include <{}>", header))
+        .collect()
+}
+
+fn parse_tags(problem: &Problem) -> String {
+    problem
+        .topic_tags
+        .iter()
+        .map(|tag| tag.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn parse_author() -> String {
+    if let Ok(author) = std::env::var("QUIP_AUTHOR") {
+        return author;
+    }
+
+    Command::new("git")
+        .args(["config", "user.name"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+        .unwrap_or_default()
+}
+
 fn parse_problem_link(problem: &Problem) -> String {
     format!("https://leetcode.com/problems/{}/", problem.title_slug)
 }
@@ -80,186 +178,801 @@ fn parse_discuss_link(problem: &Problem) -> String {
     )
 }
 
-fn build_desc(content: &str) -> String {
-    // TODO: fix this shit
-    content
-        .replace("<strong>", "")
-        .replace("</strong>", "")
-        .replace("<em>", "")
-        .replace("</em>", "")
-        .replace("</p>", "")
-        .replace("<p>", "")
-        .replace("<b>", "")
-        .replace("</b>", "")
-        .replace("<pre>", "")
-        .replace("</pre>", "")
-        .replace("<ul>", "")
-        .replace("</ul>", "")
-        .replace("<li>", "")
-        .replace("</li>", "")
-        .replace("<code>", "")
-        .replace("</code>", "")
-        .replace("<i>", "")
-        .replace("</i>", "")
-        .replace("<sub>", "")
-        .replace("</sub>", "")
-        .replace("</sup>", "")
-        .replace("<sup>", "^")
-        .replace("&nbsp;", " ")
-        .replace("&gt;", ">")
-        .replace("&lt;", "<")
-        .replace("&quot;", "\"")
-        .replace("&minus;", "-")
-        .replace("&#39;", "'")
-        .replace("\n\n", "\n")
-        .replace('\n', "\n * ")
-        .replace('\t', "  ")
-}
-
-// pub enum SolutionReturnType {
-//     Integer,
-//     Double,
-//     String,
-//     Boolean,
-//     NoReturn,
-// }
+// Turn the example inputs and the example outputs from the description into
+// assertions in `lang`. Cases whose types we can't express as literals are skipped.
+fn build_test_cases(problem: &Problem, code: &str, lang: Lang) -> String {
+    let return_type = match &problem.meta_data.return_value {
+        Some(r) => &r.return_type,
+        None => return String::new(),
+    };
+    let fn_name = match lang {
+        Lang::Rust => solution_fn_name(code),
+        Lang::Python3 => python_fn_name(code),
+        Lang::Cpp => cpp_fn_name(code),
+    };
+    let Some(fn_name) = fn_name else {
+        return String::new();
+    };
 
-fn insert_return_type(code: &str) -> String {
-    let type_re = Regex::new(r"\s+->\s+([a-zA-Z0-9<>_]+)\s+\{[\s*\n*.*]*}").unwrap();
+    let params = &problem.meta_data.params;
+    let mut cases = String::new();
+    for (values, output) in parse_test_values(&problem.example_test_cases, params.len())
+        .into_iter()
+        .zip(parse_example_outputs(&problem.content))
+    {
+        let Some(output) = output else {
+            continue;
+        };
+        let case = match lang {
+            Lang::Rust => rust_test_case(&fn_name, &values, params, &output, return_type),
+            Lang::Python3 => python_test_case(&fn_name, &values, params, &output, return_type),
+            Lang::Cpp => cpp_test_case(&fn_name, &values, params, &output, return_type),
+        };
+        if let Some(case) = case {
+            cases.push_str(&case);
+        }
+    }
+    cases
+}
 
-    // println!("Code: {}", code);
+fn rust_test_case(
+    fn_name: &str,
+    values: &[Option<Value>],
+    params: &[Param],
+    output: &Value,
+    return_type: &str,
+) -> Option<String> {
+    let args = values
+        .iter()
+        .zip(params)
+        .map(|(value, param)| to_rust_expr(value.as_ref()?, &param.param_type))
+        .collect::<Option<Vec<_>>>()?;
+    Some(format!(
+        "\n        assert_eq!(Solution::{}({}), {});",
+        fn_name,
+        args.join(", "),
+        to_rust_expr(output, return_type)?
+    ))
+}
 
-    let rtype = type_re
+fn python_test_case(
+    fn_name: &str,
+    values: &[Option<Value>],
+    params: &[Param],
+    output: &Value,
+    return_type: &str,
+) -> Option<String> {
+    let args = values
+        .iter()
+        .zip(params)
+        .map(|(value, param)| to_python_expr(value.as_ref()?, &param.param_type))
+        .collect::<Option<Vec<_>>>()?;
+    Some(format!(
+        "\n        self.assertEqual(Solution().{}({}), {})",
+        fn_name,
+        args.join(", "),
+        to_python_expr(output, return_type)?
+    ))
+}
+
+// C++ solutions take their containers by reference, so the arguments need to be variables. The
+// result is compared against a literal of its own type.
+fn cpp_test_case(
+    fn_name: &str,
+    values: &[Option<Value>],
+    params: &[Param],
+    output: &Value,
+    return_type: &str,
+) -> Option<String> {
+    let mut case = "\n    {".to_owned();
+    for (value, param) in values.iter().zip(params) {
+        case.push_str(&format!(
+            "\n        {} {} = {};",
+            to_cpp_type(&param.param_type)?,
+            param.name,
+            to_cpp_expr(value.as_ref()?, &param.param_type)?
+        ));
+    }
+    let args = params
+        .iter()
+        .map(|param| param.name.as_str())
+        .collect::<Vec<_>>();
+    case.push_str(&format!(
+        "\n        auto result = Solution().{}({});",
+        fn_name,
+        args.join(", ")
+    ));
+    case.push_str(&format!(
+        "\n        assert((result == decltype(result){}));\n    }}",
+        braced(&to_cpp_expr(output, return_type)?)
+    ));
+    Some(case)
+}
+
+fn braced(expr: &str) -> String {
+    match expr.starts_with('{') {
+        true => expr.to_owned(),
+        false => format!("{{{}}}", expr),
+    }
+}
+
+/// The name of the first method of `impl Solution`, if there is one.
+pub fn solution_fn_name(code: &str) -> Option<String> {
+    let fn_re = Regex::new(r"(?s)impl Solution\s*\{.*?pub fn ([a-z0-9_]+)\(").unwrap();
+    fn_re
         .captures(code)
-        .unwrap()
+        .map(|c| c.get(1).unwrap().as_str().to_owned())
+}
+
+/// Parse test case inputs, one JSON value per line and one line per parameter, into Rust
+/// argument lists. Cases whose values can't be expressed as Rust literals are `None`.
+pub fn parse_test_inputs(input: &str, params: &[Param]) -> Vec<Option<Vec<String>>> {
+    parse_test_values(input, params.len())
+        .iter()
+        .map(|case| {
+            case.iter()
+                .zip(params)
+                .map(|(value, param)| to_rust_expr(value.as_ref()?, &param.param_type))
+                .collect()
+        })
+        .collect()
+}
+
+// Split test case inputs into cases of `param_count` JSON values, `None` for those that don't
+// parse.
+fn parse_test_values(input: &str, param_count: usize) -> Vec<Vec<Option<Value>>> {
+    if param_count == 0 {
+        return vec![];
+    }
+
+    let values = input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str::<Value>(line).ok())
+        .collect::<Vec<_>>();
+
+    values
+        .chunks_exact(param_count)
+        .map(|case| case.to_vec())
+        .collect()
+}
+
+// One entry per example, `None` for outputs that aren't plain JSON, so they stay aligned with
+// the sample inputs.
+fn parse_example_outputs(content: &str) -> Vec<Option<Value>> {
+    let output_re = Regex::new(r"Output:?\s*</strong>:?\s*(?:<span[^>]*>)?\s*([^<\n]+)").unwrap();
+    output_re
+        .captures_iter(content)
+        .map(|c| {
+            let raw = c
+                .get(1)
+                .unwrap()
+                .as_str()
+                .replace("&quot;", "\"")
+                .replace("&#39;", "'")
+                .replace("&nbsp;", " ");
+            serde_json::from_str(raw.trim()).ok()
+        })
+        .collect()
+}
+
+/// Render a JSON value of the given LeetCode type as a Rust expression.
+pub fn to_rust_expr(value: &Value, ty: &str) -> Option<String> {
+    if let Some(inner) = element_type(ty) {
+        let items = value
+            .as_array()?
+            .iter()
+            .map(|v| to_rust_expr(v, inner))
+            .collect::<Option<Vec<_>>>()?;
+        return Some(format!("vec![{}]", items.join(", ")));
+    }
+
+    match ty {
+        "integer" | "long" => value.as_i64().map(|v| v.to_string()),
+        "double" => value.as_f64().map(|v| format!("{:?}", v)),
+        "boolean" => value.as_bool().map(|v| v.to_string()),
+        "string" | "String" => value.as_str().map(|v| format!("{:?}.to_string()", v)),
+        "character" => value
+            .as_str()
+            .and_then(|v| v.chars().next())
+            .map(|c| format!("{:?}", c)),
+        "ListNode" => {
+            let items = value
+                .as_array()?
+                .iter()
+                .map(|v| v.as_i64().map(|v| v.to_string()))
+                .collect::<Option<Vec<_>>>()?;
+            Some(format!("to_list(vec![{}])", items.join(", ")))
+        }
+        "TreeNode" => {
+            let items = value
+                .as_array()?
+                .iter()
+                .map(|v| match v {
+                    Value::Null => Some("None".to_owned()),
+                    v => v.as_i64().map(|v| format!("Some({})", v)),
+                })
+                .collect::<Option<Vec<_>>>()?;
+            if items.is_empty() {
+                return Some("None".to_owned());
+            }
+            Some(format!("to_tree(vec![{}])", items.join(", ")))
+        }
+        _ => None,
+    }
+}
+
+/// Render a JSON value of the given LeetCode type as a Python expression. Linked lists and
+/// trees aren't supported.
+pub fn to_python_expr(value: &Value, ty: &str) -> Option<String> {
+    if let Some(inner) = element_type(ty) {
+        let items = value
+            .as_array()?
+            .iter()
+            .map(|v| to_python_expr(v, inner))
+            .collect::<Option<Vec<_>>>()?;
+        return Some(format!("[{}]", items.join(", ")));
+    }
+
+    match ty {
+        "integer" | "long" => value.as_i64().map(|v| v.to_string()),
+        "double" => value.as_f64().map(|v| format!("{:?}", v)),
+        "boolean" => value
+            .as_bool()
+            .map(|v| if v { "True" } else { "False" }.to_owned()),
+        "string" | "String" | "character" => value.as_str().map(quote),
+        _ => None,
+    }
+}
+
+/// Render a JSON value of the given LeetCode type as a C++ initializer. Linked lists and trees
+/// aren't supported.
+pub fn to_cpp_expr(value: &Value, ty: &str) -> Option<String> {
+    if let Some(inner) = element_type(ty) {
+        let items = value
+            .as_array()?
+            .iter()
+            .map(|v| to_cpp_expr(v, inner))
+            .collect::<Option<Vec<_>>>()?;
+        return Some(format!("{{{}}}", items.join(", ")));
+    }
+
+    match ty {
+        "integer" | "long" => value.as_i64().map(|v| v.to_string()),
+        "double" => value.as_f64().map(|v| format!("{:?}", v)),
+        "boolean" => value.as_bool().map(|v| v.to_string()),
+        "string" | "String" => value.as_str().map(quote),
+        "character" => value
+            .as_str()
+            .and_then(|v| v.chars().next())
+            .filter(char::is_ascii)
+            .map(|c| format!("{:?}", c)),
+        _ => None,
+    }
+}
+
+fn to_cpp_type(ty: &str) -> Option<String> {
+    if let Some(inner) = element_type(ty) {
+        return Some(format!("vector<{}>", to_cpp_type(inner)?));
+    }
+
+    match ty {
+        "integer" => Some("int".to_owned()),
+        "long" => Some("long long".to_owned()),
+        "double" => Some("double".to_owned()),
+        "boolean" => Some("bool".to_owned()),
+        "string" | "String" => Some("string".to_owned()),
+        "character" => Some("char".to_owned()),
+        _ => None,
+    }
+}
+
+// The element type of an array type such as `integer[]` or `list<string>`.
+fn element_type(ty: &str) -> Option<&str> {
+    ty.strip_suffix("[]")
+        .or_else(|| ty.strip_prefix("list<")?.strip_suffix('>'))
+}
+
+// Python and C++ string literals share JSON's escapes.
+fn quote(v: &str) -> String {
+    serde_json::to_string(v).unwrap()
+}
+
+fn build_desc(content: &str, lang: Lang) -> String {
+    // The first line follows the comment prefix already in the template.
+    let prefix = match lang {
+        Lang::Python3 => "",
+        Lang::Rust | Lang::Cpp => " * ",
+    };
+    html_to_doc(content)
+        .lines()
+        .enumerate()
+        .map(|(i, line)| match i {
+            0 => line.to_owned(),
+            _ => format!("\n{}{}", prefix, line)
+                .trim_end_matches(' ')
+                .to_owned(),
+        })
+        .collect()
+}
+
+fn python_fn_name(code: &str) -> Option<String> {
+    let fn_re = Regex::new(r"(?s)class Solution\b.*?def (\w+)\(self").unwrap();
+    fn_re
+        .captures(code)
+        .map(|c| c.get(1).unwrap().as_str().to_owned())
+}
+
+fn cpp_fn_name(code: &str) -> Option<String> {
+    let fn_re = Regex::new(r"(?s)class Solution\s*\{\s*public:.*?(\w+)\(").unwrap();
+    fn_re
+        .captures(code)
+        .map(|c| c.get(1).unwrap().as_str().to_owned())
+}
+
+// LeetCode only shows the definitions of the node types in a comment, which C++ needs to compile
+// the signatures using them.
+fn uncomment_cpp_definitions(code: &str) -> String {
+    let definition_re =
+        Regex::new(r"(?s)^\s*/\*\*\s*\n\s*\* Definition for [^\n]*\n(.*?)\n\s*\*/").unwrap();
+    let Some(c) = definition_re.captures(code) else {
+        return code.to_owned();
+    };
+
+    let definition = c
         .get(1)
         .unwrap()
         .as_str()
-        .to_string();
-
-    // println!("{}", rtype);
-
-    let sblock_re = Regex::new(r"\{[\s+\n]+}").unwrap();
-
-    // match on rtypes and insert the correct return value
-
-    let syntax_tree = parse_file(code).unwrap();
-
-    // for item in syntax_tree.items {
-    //     extract_block_details(item.clone());
-    //     if let syn::Item::Fn(item_fn) = item {
-    //         if let Some(return_type) = extract_return_type(&item_fn) {
-    //             println!("Function: {}", item_fn.sig.ident);
-    //         }
-    //     }
-    // }
-    //
-    code.to_string()
-}
-
-fn extract_block_details(item: Item) {
-    if let Item::Impl(imp) = item {
-        for item in imp.items {
-            if let ImplItem::Fn(item_fn) = item {
-                // println!("\nFunction: {:#?}", item_fn);
-                let sig = &item_fn.sig;
-                let out = &sig.output;
-                // println!("Function: {:#?}", out);
-                match out {
-                    ReturnType::Default => {}
-                    ReturnType::Type(_, ty) => {
-                        println!("Return Type: {:#?}", ty);
-
-                        match **ty {
-                            Type::Path(ref path) => {
-                                // println!("Return Type: {:#?}", path);
-                                for seg in &path.path.segments {
-                                    // println!("Return Type: {:#?}", seg.ident);
-                                    match seg.ident.to_string().as_str() {
-                                        "Option" => {
-                                            println!("Option");
-                                        }
-
-                                        "Vec" => {
-                                            println!("Vec");
-                                        }
-
-                                        _ => {
-                                            println!("{}", seg.ident);
-                                        }
-                                    }
-                                }
-                            }
-                            _ => {
-                                todo!()
-                            }
-                        }
-
-                        // match ty {
-                        //     Type::Path(path) => {
-                        //         println!("Return Type: {:#?}", path);
-                        //     }
-                        //     _ => {}
-                        // }
-                        //
-                        // if ty.segments.len() == 1 {
-                        //     println!("Return Type: {:#?}", ty.segments[0].ident);
-                        // }
+        .lines()
+        .map(|line| {
+            let line = line.trim_start().trim_start_matches('*');
+            line.strip_prefix(' ').unwrap_or(line)
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!("{}{}", definition, &code[c.get(0).unwrap().end()..])
+}
+
+// A non-void C++ function that falls off its end is undefined behavior, which is how LeetCode's
+// default code comes, so give each of them a `return {};`.
+fn insert_cpp_returns(code: &str) -> String {
+    let signature_re = Regex::new(r"^\s*(.*?)\b\w+\s*\(.*\)\s*(const\s*)?\{$").unwrap();
+    let lines = code.lines().map(str::trim_end).collect::<Vec<_>>();
+
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        out.push(line.to_owned());
+        i += 1;
+
+        let Some(c) = signature_re.captures(line) else {
+            continue;
+        };
+        let return_type = c.get(1).unwrap().as_str().trim();
+        if return_type.is_empty() || return_type == "void" || return_type.starts_with(['*', '/']) {
+            continue;
+        }
+
+        // The body is empty if only blank lines come before its closing brace.
+        let body_len = lines[i..].iter().take_while(|l| l.is_empty()).count();
+        if lines.get(i + body_len).is_some_and(|l| l.trim() == "}") {
+            let indent = line.len() - line.trim_start().len();
+            out.push(format!("{}    return {{}};", " ".repeat(indent)));
+            i += body_len;
+        }
+    }
+    out.join("\n").trim_end().to_owned()
+}
+
+// Python won't parse a `def` without a body, which is how LeetCode's default code comes, so give
+// each of them a `pass`.
+fn insert_python_pass(code: &str) -> String {
+    let lines = code.lines().map(str::trim_end).collect::<Vec<_>>();
+    let indent_of = |line: &str| line.len() - line.trim_start().len();
+
+    let mut out = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        out.push(line.to_string());
+        if !(line.trim_start().starts_with("def ") && line.ends_with(':')) {
+            continue;
+        }
+        let has_body = lines[i + 1..]
+            .iter()
+            .find(|next| !next.is_empty())
+            .is_some_and(|next| indent_of(next) > indent_of(line));
+        if !has_body {
+            out.push(format!("{}    pass", " ".repeat(indent_of(line))));
+        }
+    }
+    out.join("\n").trim_end().to_owned()
+}
+
+/// Fill the empty function bodies of LeetCode's default code with a value of their return type,
+/// so the generated file compiles before the problem is solved. The comments around the code
+/// (node definitions, usage examples) are kept as they are. Code that doesn't parse is returned
+/// unchanged.
+fn insert_default_returns(code: &str) -> String {
+    let (header, body, footer) = split_comments(code);
+    let Ok(mut file) = parse_file(body) else {
+        return code.to_owned();
+    };
+
+    let structs = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Struct(s) => Some((s.ident.to_string(), s.fields.clone())),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    for item in &mut file.items {
+        match item {
+            Item::Fn(f) => fill_body(&f.sig.output, &mut f.block, None),
+            Item::Impl(imp) => {
+                let self_fields = match &*imp.self_ty {
+                    Type::Path(p) => p
+                        .path
+                        .get_ident()
+                        .and_then(|ident| structs.get(&ident.to_string())),
+                    _ => None,
+                };
+                for impl_item in &mut imp.items {
+                    if let ImplItem::Fn(f) = impl_item {
+                        fill_body(&f.sig.output, &mut f.block, self_fields);
                     }
                 }
             }
+            _ => {}
+        }
+    }
+
+    let code = prettyplease::unparse(&file);
+    if footer.is_empty() {
+        format!("{}{}", header, code.trim_end())
+    } else {
+        format!("{}{}\n{}", header, code, footer)
+    }
+}
+
+// Split off the leading line comments and a trailing block comment, which syn would either drop
+// or refuse to parse.
+fn split_comments(code: &str) -> (&str, &str, &str) {
+    let mut header_len = 0;
+    for line in code.split_inclusive('\n') {
+        let line_trimmed = line.trim();
+        if !(line_trimmed.is_empty() || line_trimmed.starts_with("//")) {
+            break;
         }
+        header_len += line.len();
+    }
+    let (header, rest) = code.split_at(header_len);
+
+    let rest = rest.trim_end();
+    match rest.rfind("/*") {
+        Some(start) if rest.ends_with("*/") && rest[..start].trim_end().ends_with('}') => {
+            (header, &rest[..start], &rest[start..])
+        }
+        _ => (header, rest, ""),
+    }
+}
+
+fn fill_body(output: &ReturnType, block: &mut Block, self_fields: Option<&Fields>) {
+    if !block.stmts.is_empty() {
+        return;
+    }
+
+    if let ReturnType::Type(_, ty) = output {
+        let expr = parse_str::<Expr>(&default_expr(ty, self_fields)).unwrap();
+        block.stmts.push(Stmt::Expr(expr, None));
+    }
+}
+
+// A placeholder value of type `ty`. `self_fields` are the fields of the impl's type, used to
+// build `Self` in constructors of design problems.
+fn default_expr(ty: &Type, self_fields: Option<&Fields>) -> String {
+    let segment = match ty {
+        Type::Tuple(tuple) => {
+            let elems = tuple
+                .elems
+                .iter()
+                .map(|elem| default_expr(elem, self_fields))
+                .collect::<Vec<_>>();
+            return match elems.len() {
+                1 => format!("({},)", elems[0]),
+                _ => format!("({})", elems.join(", ")),
+            };
+        }
+        Type::Path(p) => p.path.segments.last().unwrap(),
+        _ => return "todo!()".to_owned(),
+    };
+
+    let inner = match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        }),
+        _ => None,
+    };
+
+    let ident = segment.ident.to_string();
+    match ident.as_str() {
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "u128"
+        | "usize" => "0".to_owned(),
+        "f32" | "f64" => "0.0".to_owned(),
+        "bool" => "false".to_owned(),
+        "char" => "'0'".to_owned(),
+        "String" => "String::new()".to_owned(),
+        "Vec" => "vec![]".to_owned(),
+        "Option" => "None".to_owned(),
+        "HashMap" | "HashSet" | "BTreeMap" | "BTreeSet" | "VecDeque" | "BinaryHeap" => {
+            "Default::default()".to_owned()
+        }
+        "Box" | "Rc" | "RefCell" => match inner {
+            Some(inner) => format!("{}::new({})", ident, default_expr(inner, self_fields)),
+            None => "todo!()".to_owned(),
+        },
+        "TreeNode" | "ListNode" => format!("{}::new(0)", ident),
+        "Self" => match self_fields {
+            Some(Fields::Named(fields)) => format!(
+                "Self {{ {} }}",
+                fields
+                    .named
+                    .iter()
+                    .map(|f| format!(
+                        "{}: {}",
+                        f.ident.as_ref().unwrap(),
+                        default_expr(&f.ty, None)
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Some(Fields::Unnamed(fields)) => format!(
+                "Self({})",
+                fields
+                    .unnamed
+                    .iter()
+                    .map(|f| default_expr(&f.ty, None))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Some(Fields::Unit) => "Self".to_owned(),
+            None => "todo!()".to_owned(),
+        },
+        _ => "todo!()".to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TREE_CODE: &str = "// Definition for a binary tree node.
+// #[derive(Debug, PartialEq, Eq)]
+// pub struct TreeNode {
+//   pub val: i32,
+// }
+//
+// impl TreeNode {
+//   #[inline]
+//   pub fn new(val: i32) -> Self {
+//     TreeNode { val }
+//   }
+// }
+use std::rc::Rc;
+use std::cell::RefCell;
+impl Solution {
+    pub fn max_depth(root: Option<Rc<RefCell<TreeNode>>>) -> i32 {
+        
     }
+}";
+
+    const DESIGN_CODE: &str = "struct MinStack {
+
 }
 
-fn extract_return_type(item_fn: &ItemFn) -> Option<&Type> {
-    match &item_fn.sig.output {
-        ReturnType::Default => None,
-        ReturnType::Type(_, ty) => Some(ty),
-    }
-}
-
-fn insert_return_in_code(return_type: &str, code: &str) -> String {
-    // let tre = Regex::new(r"([a-zA-Z0-9]+)\s\{[ \n]+}").unwrap();
-
-    let re = Regex::new(r"\{[ \n]+}").unwrap();
-
-    match return_type {
-        "ListNode" => re
-            .replace(code, "{\n        Some(Box::new(ListNode::new(0)))\n    }")
-            .to_string(),
-        "ListNode[]" => re.replace(code, "{\n        vec![]\n    }").to_string(),
-        "TreeNode" => re
-            .replace(
-                code,
-                "{\n        Some(Rc::new(RefCell::new(TreeNode::new(0))))\n    }",
-            )
-            .to_string(),
-        "boolean" => re.replace(code, "{\n        false\n    }").to_string(),
-        "character" => re.replace(code, "{\n        '0'\n    }").to_string(),
-        "character[][]" => re.replace(code, "{\n        vec![]\n    }").to_string(),
-        "double" => re.replace(code, "{\n        0f64\n    }").to_string(),
-        "double[]" => re.replace(code, "{\n        vec![]\n    }").to_string(),
-        "int[]" => re.replace(code, "{\n        vec![]\n    }").to_string(),
-        "integer" => re.replace(code, "{\n        0\n    }").to_string(),
-        "integer[]" => re.replace(code, "{\n        vec![]\n    }").to_string(),
-        "integer[][]" => re.replace(code, "{\n        vec![]\n    }").to_string(),
-        "list<String>" => re.replace(code, "{\n        vec![]\n    }").to_string(),
-        "list<TreeNode>" => re.replace(code, "{\n        vec![]\n    }").to_string(),
-        "list<boolean>" => re.replace(code, "{\n        vec![]\n    }").to_string(),
-        "list<double>" => re.replace(code, "{\n        vec![]\n    }").to_string(),
-        "list<integer>" => re.replace(code, "{\n        vec![]\n    }").to_string(),
-        "list<list<integer>>" => re.replace(code, "{\n        vec![]\n    }").to_string(),
-        "list<list<string>>" => re.replace(code, "{\n        vec![]\n    }").to_string(),
-        "list<string>" => re.replace(code, "{\n        vec![]\n    }").to_string(),
-        "null" => code.to_string(),
-        "string" => re
-            .replace(code, "{\n        String::new()\n    }")
-            .to_string(),
-        "string[]" => re.replace(code, "{\n        vec![]\n    }").to_string(),
-        "void" => code.to_string(),
-        "NestedInteger" => code.to_string(),
-        "Node" => code.to_string(),
-        _ => code.to_string(),
+
+/**
+ * `&self` means the method takes an immutable reference.
+ * If you need a mutable reference, change it to `&mut self` instead.
+ */
+impl MinStack {
+
+    fn new() -> Self {
+        
+    }
+    
+    fn push(&self, val: i32) {
+        
+    }
+    
+    fn top(&self) -> i32 {
+        
+    }
+}
+
+/**
+ * Your MinStack object will be instantiated and called as such:
+ * let obj = MinStack::new();
+ * obj.push(val);
+ */";
+
+    fn default_of(ty: &str) -> String {
+        default_expr(&parse_str::<Type>(ty).unwrap(), None)
+    }
+
+    #[test]
+    fn test_split_comments() {
+        let (header, body, footer) = split_comments(TREE_CODE);
+        assert!(header.ends_with("// }\n"));
+        assert!(body.starts_with("use std::rc::Rc;"));
+        assert_eq!(footer, "");
+
+        let (header, body, footer) = split_comments(DESIGN_CODE);
+        assert_eq!(header, "");
+        assert!(body.trim_end().ends_with('}'));
+        assert!(footer.starts_with("/**\n * Your MinStack object"));
+        assert!(footer.ends_with("*/"));
+    }
+
+    #[test]
+    fn test_default_expr() {
+        assert_eq!(default_of("i64"), "0");
+        assert_eq!(default_of("f64"), "0.0");
+        assert_eq!(default_of("bool"), "false");
+        assert_eq!(default_of("String"), "String::new()");
+        assert_eq!(default_of("Vec<Vec<i32>>"), "vec![]");
+        assert_eq!(default_of("Option<Box<ListNode>>"), "None");
+        assert_eq!(
+            default_of("Rc<RefCell<TreeNode>>"),
+            "Rc::new(RefCell::new(TreeNode::new(0)))"
+        );
+        assert_eq!(default_of("(i32, String)"), "(0, String::new())");
+        assert_eq!(default_of("(bool,)"), "(false,)");
+        assert_eq!(default_of("HashMap<i32, i32>"), "Default::default()");
+        assert_eq!(default_of("Self"), "todo!()");
+        assert_eq!(default_of("&str"), "todo!()");
+    }
+
+    #[test]
+    fn test_insert_default_returns() {
+        let code = insert_default_returns(TREE_CODE);
+        assert!(code.starts_with("// Definition for a binary tree node."));
+        assert!(code.contains("//   pub fn new(val: i32) -> Self {"));
+        assert!(code.ends_with(
+            "    pub fn max_depth(root: Option<Rc<RefCell<TreeNode>>>) -> i32 {\n        0\n    }\n}"
+        ));
+
+        let code = insert_default_returns(DESIGN_CODE);
+        assert!(code.contains("    fn new() -> Self {\n        Self {}\n    }"));
+        assert!(code.contains("    fn push(&self, val: i32) {}"));
+        assert!(code.contains("    fn top(&self) -> i32 {\n        0\n    }"));
+        assert!(code.ends_with(" * obj.push(val);\n */"));
+        // The usage example documents whatever item the template puts after the code.
+        assert!(parse_file(&format!("{}\nmod tests {{}}", code)).is_ok());
+    }
+
+    #[test]
+    fn test_insert_default_returns_self_fields() {
+        let code = insert_default_returns(
+            "struct Counter {\n    hits: Vec<i32>,\n    total: i64,\n}\nimpl Counter {\n    fn new() -> Self {\n    }\n}",
+        );
+        assert!(code.contains("Self { hits: vec![], total: 0 }"));
+    }
+
+    #[test]
+    fn test_insert_default_returns_keeps_unparsable_code() {
+        let code = "impl Solution {\n    pub fn f(&self) -> i32 {\n";
+        assert_eq!(insert_default_returns(code), code);
+    }
+
+    #[test]
+    fn test_solution_fn_name() {
+        assert_eq!(solution_fn_name(TREE_CODE).as_deref(), Some("max_depth"));
+        assert_eq!(solution_fn_name(DESIGN_CODE), None);
+    }
+
+    #[test]
+    fn test_insert_cpp_returns() {
+        let code = insert_cpp_returns(
+            "class MinStack {\npublic:\n    MinStack() {\n        \n    }\n    \n    void push(int val) {\n        \n    }\n    \n    vector<int> top() const {\n        \n    }\n};",
+        );
+        assert_eq!(
+            code,
+            "class MinStack {\npublic:\n    MinStack() {\n\n    }\n\n    void push(int val) {\n\n    }\n\n    vector<int> top() const {\n        return {};\n    }\n};"
+        );
+    }
+
+    #[test]
+    fn test_uncomment_cpp_definitions() {
+        let code = uncomment_cpp_definitions(
+            "/**\n * Definition for singly-linked list.\n * struct ListNode {\n *     int val;\n *     ListNode *next;\n * };\n */\nclass Solution {\n};",
+        );
+        assert_eq!(
+            code,
+            "struct ListNode {\n    int val;\n    ListNode *next;\n};\nclass Solution {\n};"
+        );
+    }
+
+    #[test]
+    fn test_parse_cpp_includes() {
+        assert_eq!(
+            parse_cpp_includes("vector<string> f(unordered_map<int, int>& m, ListNode* head)"),
+            "\n#include <string>\n#include <unordered_map>\n#include <vector>"
+        );
+    }
+
+    #[test]
+    fn test_insert_python_pass() {
+        let code = insert_python_pass(
+            "class MinStack:\n\n    def __init__(self):\n        \n\n    def push(self, val: int) -> None:\n        ",
+        );
+        assert_eq!(
+            code,
+            "class MinStack:\n\n    def __init__(self):\n        pass\n\n\n    def push(self, val: int) -> None:\n        pass"
+        );
+    }
+
+    fn rust_expr(json: &str, ty: &str) -> Option<String> {
+        to_rust_expr(&serde_json::from_str(json).unwrap(), ty)
+    }
+
+    #[test]
+    fn test_to_rust_expr() {
+        assert_eq!(
+            rust_expr("[1,2,3]", "integer[]").as_deref(),
+            Some("vec![1, 2, 3]")
+        );
+        assert_eq!(
+            rust_expr("[[1],[]]", "list<list<integer>>").as_deref(),
+            Some("vec![vec![1], vec![]]")
+        );
+        assert_eq!(
+            rust_expr(r#"["ab","c"]"#, "string[]").as_deref(),
+            Some(r#"vec!["ab".to_string(), "c".to_string()]"#)
+        );
+        assert_eq!(rust_expr(r#""x""#, "character").as_deref(), Some("'x'"));
+        assert_eq!(rust_expr("2.5", "double").as_deref(), Some("2.5"));
+        assert_eq!(
+            rust_expr("[1,2]", "ListNode").as_deref(),
+            Some("to_list(vec![1, 2])")
+        );
+        assert_eq!(
+            rust_expr("[1,null,2]", "TreeNode").as_deref(),
+            Some("to_tree(vec![Some(1), None, Some(2)])")
+        );
+        assert_eq!(rust_expr("[]", "TreeNode").as_deref(), Some("None"));
+        assert_eq!(rust_expr("[1,\"a\"]", "integer[]"), None);
+        assert_eq!(rust_expr("1", "Node"), None);
+    }
+
+    #[test]
+    fn test_parse_test_values() {
+        let cases = parse_test_values("[2,7,11,15]\n9\n\n[3,2,4]\n6\n[3,3]", 2);
+        assert_eq!(
+            cases,
+            vec![
+                vec![
+                    Some(serde_json::json!([2, 7, 11, 15])),
+                    Some(Value::from(9))
+                ],
+                vec![Some(serde_json::json!([3, 2, 4])), Some(Value::from(6))],
+            ]
+        );
+
+        let cases = parse_test_values("[1,\n2", 1);
+        assert_eq!(cases, vec![vec![None], vec![Some(Value::from(2))]]);
+        assert!(parse_test_values("1", 0).is_empty());
+    }
+
+    #[test]
+    fn test_example_outputs_stay_aligned() {
+        let content = "<strong>Output:</strong> 2, nums = [1,_]\n\
+                       <strong>Output:</strong> 5\n";
+        assert_eq!(
+            parse_example_outputs(content),
+            vec![None, Some(Value::from(5))]
+        );
     }
 }
@@ -1,35 +1,108 @@
 use regex::Regex;
-use serde_json::Value;
+use serde::de::DeserializeOwned;
 use std::fmt::Error;
 use std::fs;
+use std::path::{Path, PathBuf};
 
-use crate::common::problem::{Problem, Query, RawProblem, UserProblems};
-use crate::common::{GRAPHQL_URL, PROBLEMS_URL};
+use crate::common::auth;
+use crate::common::cache;
+use crate::common::lang::Lang;
+use crate::common::modules::list_modules;
+use crate::common::problem::{
+    Problem, Query, Question, RawDailyProblem, RawProblem, RawTaggedProblems, RawUserStatus,
+    Submission, SubmissionCheck, SubmissionId, UserProblems, UserStatus,
+};
+use crate::common::{GRAPHQL_URL, LEETCODE_URL, PROBLEMS_URL};
 
 use super::problem::StatWithStatus;
 
-pub fn get_initialized_problems() -> Vec<u32> {
-    let content = fs::read_to_string("./src/problem/mod.rs").unwrap();
-    let id_pattern = Regex::new(r"p(\d{4})_").unwrap();
-    id_pattern
-        .captures_iter(&content)
-        .map(|x| x.get(1).unwrap().as_str().parse().unwrap())
-        .collect()
+/// Ids of every problem declared in either `src/problem` or `src/solution`.
+pub fn get_initialized_problems() -> anyhow::Result<Vec<u32>> {
+    let mut ids = get_declared_problems(Path::new("./src/problem"))?;
+    ids.extend(get_declared_problems(Path::new("./src/solution"))?);
+    Ok(ids)
+}
+
+/// Ids of the problems declared in `dir/mod.rs`.
+pub fn get_declared_problems(dir: &Path) -> anyhow::Result<Vec<u32>> {
+    let id_pattern = Regex::new(r"^p(\d{4})_").unwrap();
+    Ok(list_modules(dir)?
+        .iter()
+        .filter_map(|name| {
+            id_pattern
+                .captures(name)
+                .map(|x| x.get(1).unwrap().as_str().parse().unwrap())
+        })
+        .collect())
+}
+
+/// Ids of the problems pulled in `lang`. Rust problems are read from the mod.rs files, the
+/// other languages from the names of the files in their directories.
+pub fn get_pulled_problems(lang: Lang) -> anyhow::Result<Vec<u32>> {
+    if lang == Lang::Rust {
+        return get_initialized_problems();
+    }
+
+    let id_pattern = Regex::new(r"^p(\d{4})_").unwrap();
+    Ok([lang.problem_dir(), lang.solution_dir()]
+        .iter()
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name();
+            let captures = id_pattern.captures(name.to_str()?)?;
+            captures.get(1).unwrap().as_str().parse().ok()
+        })
+        .collect())
+}
+
+/// Find the file of a problem pulled in `lang`, in either its problem or solution directory.
+pub fn find_problem_file(question_id: u32, lang: Lang) -> Option<PathBuf> {
+    let prefix = format!("p{:04}_", question_id);
+    let extension = format!(".{}", lang.extension());
+    [lang.problem_dir(), lang.solution_dir()]
+        .iter()
+        .find_map(|dir| {
+            fs::read_dir(dir)
+                .ok()?
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .find(|path| {
+                    path.file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| name.starts_with(&prefix) && name.ends_with(&extension))
+                })
+        })
+}
+
+/// Why there's no Rust file for a problem, for the commands that only work on Rust problems.
+pub fn missing_rust_problem_message(question_id: u32) -> String {
+    let other_lang = [Lang::Python3, Lang::Cpp]
+        .into_iter()
+        .find(|lang| find_problem_file(question_id, *lang).is_some());
+    match other_lang {
+        Some(lang) => format!(
+            "Problem #{} was pulled in {}, this command only supports Rust.",
+            question_id,
+            lang.slug()
+        ),
+        None => format!("Problem #{} has not been pulled yet.", question_id),
+    }
 }
 
 async fn init_client() -> Result<(reqwest::Client, reqwest::header::HeaderMap), Error> {
+    init_client_with_cookie(auth::load_cookie()).await
+}
+
+async fn init_client_with_cookie(
+    maybe_cookie: Option<String>,
+) -> Result<(reqwest::Client, reqwest::header::HeaderMap), Error> {
     let client = reqwest::Client::builder()
         .build()
         .expect("Failed to build client");
     let mut headers = reqwest::header::HeaderMap::new();
 
     // Insert cookie only if it exists.
-    // TODO: Figure out how to automatically connect to leetcode without manual cookie.
-    let maybe_cookie = match std::env::var("LEETCODE_COOKIE") {
-        Ok(val) => Some(val),
-        Err(_) => None,
-    };
-
     if let Some(cookie) = maybe_cookie {
         headers.insert(
             "Cookie",
@@ -41,13 +114,15 @@ async fn init_client() -> Result<(reqwest::Client, reqwest::header::HeaderMap),
     Ok((client, headers))
 }
 
-pub async fn get_problem(_question_id: u32) -> Option<Problem> {
-    let problems = get_user_problems().await.unwrap();
+/// Get a problem statement, from the local cache unless `refresh` is set. The problem list is
+/// always read through the cache; refresh it with `get_user_problems` first if needed.
+pub async fn get_problem(_question_id: u32, refresh: bool) -> Option<Problem> {
+    let problems = get_user_problems(false).await?;
 
     for problem_stat in problems.stat_status_pairs.iter() {
         if problem_stat.stat.frontend_question_id == _question_id {
             // return Some(problem.stat.clone());
-            if let Some(problem) = get_problem_request(problem_stat).await.unwrap() {
+            if let Some(problem) = get_problem_request(problem_stat, refresh).await.unwrap() {
                 return Some(problem);
             }
         }
@@ -57,45 +132,132 @@ pub async fn get_problem(_question_id: u32) -> Option<Problem> {
 
 async fn get_problem_request(
     problem: &StatWithStatus,
+    refresh: bool,
 ) -> Result<Option<Problem>, Box<dyn std::error::Error>> {
-    let (client, headers) = init_client().await?;
+    let title_slug = problem.stat.question_title_slug.as_ref().unwrap();
 
-    let resp: RawProblem = client
-        .post(GRAPHQL_URL)
-        .headers(headers)
-        .json(&Query::question_query(
-            problem.stat.question_title_slug.as_ref().unwrap(),
-        ))
-        .send()
-        .await
-        .unwrap()
-        .json()
-        .await
-        .unwrap();
+    let question = match cache::load_question(title_slug) {
+        Some(question) if !refresh => question,
+        _ => {
+            let question = get_question_request(title_slug).await?;
+            // The statement is still good to use if it can't be cached.
+            let _ = cache::store_question(title_slug, &question);
+            question
+        }
+    };
+
+    let example_test_cases = match question.example_testcases.is_empty() {
+        true => question.sample_test_case.clone(),
+        false => question.example_testcases,
+    };
 
     Ok(Some(Problem {
         title: problem.stat.question_title.clone().unwrap(),
-        title_slug: problem.stat.question_title_slug.clone().unwrap(),
-        code_definition: serde_json::from_str(&resp.data.question.code_definition).unwrap(),
-        content: resp.data.question.content,
-        sample_test_case: resp.data.question.sample_test_case,
+        title_slug: title_slug.clone(),
+        code_definition: serde_json::from_str(&question.code_definition).unwrap(),
+        content: question.content,
+        sample_test_case: question.sample_test_case,
+        example_test_cases,
         difficulty: problem.difficulty.to_string(),
         question_id: problem.stat.frontend_question_id,
-        return_type: {
-            let v: Value = serde_json::from_str(&resp.data.question.meta_data).unwrap();
-            v["returnType"].to_string().replace('\"', "")
-        },
+        meta_data: serde_json::from_str(&question.meta_data).unwrap(),
+        topic_tags: question.topic_tags,
     }))
 }
 
-pub async fn get_user_problems() -> Option<UserProblems> {
-    // let res = reqwest::get(PROBLEMS_URL).await.unwrap();
-    let res = get_problems_request().await.unwrap();
+pub async fn get_question_request(
+    title_slug: &str,
+) -> Result<Question, Box<dyn std::error::Error>> {
+    let resp: RawProblem = graphql_request(&Query::question_query(title_slug)).await?;
+    Ok(resp.data.question)
+}
 
-    let problems = serde_json::from_str::<UserProblems>(&res).unwrap();
-    // println!("{:?}", problems);
+pub async fn get_daily_problem_id() -> Result<u32, Box<dyn std::error::Error>> {
+    let resp: RawDailyProblem = graphql_request(&Query::daily_query()).await?;
+    let id = resp
+        .data
+        .active_daily_coding_challenge_question
+        .question
+        .frontend_question_id;
+    Ok(id.parse()?)
+}
 
-    Some(problems)
+pub async fn get_tagged_problem_ids(tag: &str) -> Result<Vec<u32>, Box<dyn std::error::Error>> {
+    let resp: RawTaggedProblems = graphql_request(&Query::tagged_query(tag)).await?;
+    Ok(resp
+        .data
+        .problemset_question_list
+        .questions
+        .iter()
+        .filter_map(|q| q.frontend_question_id.parse().ok())
+        .collect())
+}
+
+async fn graphql_request<T: DeserializeOwned>(
+    query: &Query,
+) -> Result<T, Box<dyn std::error::Error>> {
+    let (client, headers) = init_client().await?;
+
+    let resp = client
+        .post(GRAPHQL_URL)
+        .headers(headers)
+        .json(query)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    Ok(resp)
+}
+
+/// Ask LeetCode who `cookie` belongs to, to check that it's a valid session.
+pub async fn get_user_status(cookie: &str) -> Result<UserStatus, Box<dyn std::error::Error>> {
+    let (client, headers) = init_client_with_cookie(Some(cookie.to_owned())).await?;
+
+    let resp: RawUserStatus = client
+        .post(GRAPHQL_URL)
+        .headers(headers)
+        .json(&Query::user_status_query())
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    Ok(resp.data.user_status)
+}
+
+/// Get the problem list from the local cache, or from LeetCode when it isn't cached yet or
+/// `refresh` is set. Falls back to the cache if the request fails.
+pub async fn get_user_problems(refresh: bool) -> Option<UserProblems> {
+    if !refresh {
+        if let Some(problems) = cache::load_problems() {
+            return Some(problems);
+        }
+    }
+
+    let fetched = get_problems_request().await.ok().and_then(|body| {
+        let problems = serde_json::from_str::<UserProblems>(&body).ok()?;
+        Some((body, problems))
+    });
+    match fetched {
+        Some((body, problems)) => {
+            // The list is still good to use if it can't be cached.
+            let _ = cache::store_problems(&body);
+            Some(problems)
+        }
+        None => cache::load_problems(),
+    }
+}
+
+/// Fetch the problem list from LeetCode and store it in the cache. Unlike `get_user_problems`,
+/// this never falls back to the cache.
+pub async fn refresh_user_problems() -> Result<UserProblems, Box<dyn std::error::Error>> {
+    let body = get_problems_request().await?;
+    let problems = serde_json::from_str(&body)?;
+    cache::store_problems(&body)?;
+    Ok(problems)
 }
 
 async fn get_problems_request() -> Result<String, Box<dyn std::error::Error>> {
@@ -110,3 +272,75 @@ async fn get_problems_request() -> Result<String, Box<dyn std::error::Error>> {
 
     Ok(body)
 }
+
+pub async fn get_problem_stat(question_id: u32) -> Option<StatWithStatus> {
+    let problems = get_user_problems(false).await?;
+    problems
+        .stat_status_pairs
+        .into_iter()
+        .find(|p| p.stat.frontend_question_id == question_id)
+}
+
+// LeetCode rejects submissions without a csrf token matching the one in the session cookie.
+fn get_csrf_token() -> Option<String> {
+    let cookie = auth::load_cookie()?;
+    let token_pattern = Regex::new(r"csrftoken=([^;\s]+)").unwrap();
+    token_pattern
+        .captures(&cookie)
+        .map(|c| c.get(1).unwrap().as_str().to_owned())
+}
+
+pub async fn submit_solution(
+    problem: &StatWithStatus,
+    code: &str,
+) -> Result<u64, Box<dyn std::error::Error>> {
+    let (client, mut headers) = init_client().await?;
+
+    let title_slug = problem
+        .stat
+        .question_title_slug
+        .as_ref()
+        .ok_or("problem has no title slug")?;
+    let problem_url = format!("{}/problems/{}/", LEETCODE_URL, title_slug);
+
+    headers.insert("Referer", problem_url.parse()?);
+    if let Some(token) = get_csrf_token() {
+        headers.insert("x-csrftoken", token.parse()?);
+    }
+
+    let resp: SubmissionId = client
+        .post(format!("{}submit/", problem_url))
+        .headers(headers)
+        .json(&Submission {
+            lang: "rust".to_owned(),
+            question_id: problem.stat.question_id.to_string(),
+            typed_code: code.to_owned(),
+        })
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    Ok(resp.submission_id)
+}
+
+pub async fn check_submission(
+    submission_id: u64,
+) -> Result<SubmissionCheck, Box<dyn std::error::Error>> {
+    let (client, headers) = init_client().await?;
+
+    let check = client
+        .get(format!(
+            "{}/submissions/detail/{}/check/",
+            LEETCODE_URL, submission_id
+        ))
+        .headers(headers)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    Ok(check)
+}
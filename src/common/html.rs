@@ -0,0 +1,271 @@
+use std::mem;
+
+use scraper::{ElementRef, Html, Node, Selector};
+
+/// Convert a LeetCode problem description into markdown suitable for a doc comment.
+///
+/// Emphasis, inline code and links become their markdown equivalents, `<pre>` blocks become
+/// fenced code blocks, lists (including nested and ordered ones) and tables keep their
+/// structure, and `<sup>`/`<sub>` are written as `^x`/`_x`.
+pub fn html_to_doc(content: &str) -> String {
+    let fragment = Html::parse_fragment(content);
+    let mut writer = DocWriter::default();
+    writer.walk(fragment.root_element());
+
+    let mut doc = String::new();
+    for line in writer.out.lines().map(str::trim_end) {
+        if line.is_empty() && (doc.is_empty() || doc.ends_with("\n\n")) {
+            continue;
+        }
+        doc.push_str(line);
+        doc.push('\n');
+    }
+
+    // The description ends up inside a `/** */` block, which must not be closed early or open a
+    // nested comment.
+    doc.trim()
+        .replace("/*", "/ *")
+        .replace("*/", "* /")
+        .replace('\t', "  ")
+}
+
+#[derive(Default)]
+struct DocWriter {
+    out: String,
+    // One entry per open list: `None` for `<ul>`, the next item number for `<ol>`.
+    lists: Vec<Option<usize>>,
+    in_pre: bool,
+}
+
+impl DocWriter {
+    fn walk(&mut self, element: ElementRef) {
+        for child in element.children() {
+            match child.value() {
+                Node::Text(text) => self.text(text),
+                Node::Element(_) => self.element(ElementRef::wrap(child).unwrap()),
+                _ => {}
+            }
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.in_pre {
+            self.out.push_str(text);
+            return;
+        }
+
+        for c in text.chars() {
+            if !c.is_whitespace() {
+                self.out.push(c);
+            } else if !(self.out.is_empty() || self.out.ends_with([' ', '\n'])) {
+                self.out.push(' ');
+            }
+        }
+    }
+
+    fn element(&mut self, element: ElementRef) {
+        let el = element.value();
+        match el.name() {
+            "p" | "div" => {
+                self.block();
+                self.walk(element);
+                self.block();
+            }
+            "strong" | "b" => self.inline(element, "**", "**"),
+            "em" | "i" => self.inline(element, "*", "*"),
+            "code" => self.inline(element, "`", "`"),
+            "a" => match el.attr("href") {
+                Some(href) => self.inline(element, "[", &format!("]({})", href)),
+                None => self.walk(element),
+            },
+            "sup" => {
+                self.out.push('^');
+                self.walk(element);
+            }
+            "sub" => {
+                self.out.push('_');
+                self.walk(element);
+            }
+            "br" => self.newline(),
+            "img" => self.out.push_str(&format!(
+                "![{}]({})",
+                el.attr("alt").unwrap_or_default(),
+                el.attr("src").unwrap_or_default()
+            )),
+            "pre" => {
+                self.block();
+                self.out.push_str("```text\n");
+                self.in_pre = true;
+                self.walk(element);
+                self.in_pre = false;
+                self.newline();
+                self.out.push_str("```");
+                self.block();
+            }
+            "ul" => self.list(element, None),
+            "ol" => self.list(element, Some(1)),
+            "table" => self.table(element),
+            _ => self.walk(element),
+        }
+    }
+
+    // Wrap the element's text in markers, keeping the surrounding whitespace outside of them
+    // so markdown still recognises the emphasis. Markers are meaningless inside code blocks.
+    fn inline(&mut self, element: ElementRef, open: &str, close: &str) {
+        if self.in_pre {
+            self.walk(element);
+            return;
+        }
+
+        let inner = self.render(element);
+        if inner.trim().is_empty() {
+            self.out.push_str(&inner);
+            return;
+        }
+
+        if inner.starts_with(' ') && !self.out.ends_with([' ', '\n']) {
+            self.out.push(' ');
+        }
+        self.out.push_str(open);
+        self.out.push_str(inner.trim());
+        self.out.push_str(close);
+        if inner.ends_with(' ') {
+            self.out.push(' ');
+        }
+    }
+
+    fn list(&mut self, element: ElementRef, start: Option<usize>) {
+        if self.lists.is_empty() {
+            self.block();
+        }
+        self.lists.push(start);
+
+        for item in element
+            .child_elements()
+            .filter(|e| e.value().name() == "li")
+        {
+            self.newline();
+            self.out.push_str(&"    ".repeat(self.lists.len() - 1));
+            match self.lists.last_mut().unwrap() {
+                Some(n) => {
+                    self.out.push_str(&format!("{}. ", n));
+                    *n += 1;
+                }
+                None => self.out.push_str("- "),
+            }
+            self.walk(item);
+        }
+
+        self.lists.pop();
+        if self.lists.is_empty() {
+            self.block();
+        }
+    }
+
+    fn table(&mut self, element: ElementRef) {
+        let row_selector = Selector::parse("tr").unwrap();
+
+        self.block();
+        for (i, row) in element.select(&row_selector).enumerate() {
+            let cells = row
+                .child_elements()
+                .filter(|e| matches!(e.value().name(), "th" | "td"))
+                .map(|cell| {
+                    self.render(cell)
+                        .trim()
+                        .replace('|', "\\|")
+                        .replace('\n', " ")
+                })
+                .collect::<Vec<_>>();
+
+            self.out.push_str(&format!("| {} |\n", cells.join(" | ")));
+            if i == 0 {
+                self.out
+                    .push_str(&format!("|{}\n", " --- |".repeat(cells.len())));
+            }
+        }
+        self.block();
+    }
+
+    // Render an element into a separate buffer and return the result.
+    fn render(&mut self, element: ElementRef) -> String {
+        let saved = mem::take(&mut self.out);
+        self.walk(element);
+        mem::replace(&mut self.out, saved)
+    }
+
+    fn trim_trailing_spaces(&mut self) {
+        let len = self.out.trim_end_matches(' ').len();
+        self.out.truncate(len);
+    }
+
+    fn newline(&mut self) {
+        self.trim_trailing_spaces();
+        if !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.out.push('\n');
+        }
+    }
+
+    fn block(&mut self) {
+        self.newline();
+        if !self.out.is_empty() && !self.out.ends_with("\n\n") {
+            self.out.push('\n');
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comment_delimiters() {
+        let doc =
+            html_to_doc("<p>Remove <code>/* block */</code> and <code>//</code> comments.</p>");
+        assert_eq!(doc, "Remove `/ * block * /` and `//` comments.");
+
+        let file = format!(
+            "/**\n * {}\n */\nfn f() {{}}",
+            html_to_doc("<pre>/*/ x */ /* y</pre>")
+        );
+        assert!(syn::parse_file(&file).is_ok());
+    }
+
+    #[test]
+    fn test_inline() {
+        assert_eq!(
+            html_to_doc("<p>Return <strong>the sum</strong> of <em> nums</em>, see <a href=\"/x\">this</a>.</p>"),
+            "Return **the sum** of *nums*, see [this](/x)."
+        );
+        assert_eq!(
+            html_to_doc("<p>1 &lt;= n &lt;= 10<sup>5</sup></p>"),
+            "1 <= n <= 10^5"
+        );
+    }
+
+    #[test]
+    fn test_pre() {
+        assert_eq!(
+            html_to_doc("<pre><strong>Input:</strong> n = 2\n<strong>Output:</strong> 4</pre>"),
+            "```text\nInput: n = 2\nOutput: 4\n```"
+        );
+    }
+
+    #[test]
+    fn test_nested_lists() {
+        assert_eq!(
+            html_to_doc("<ul><li>a<ol><li>b</li><li>c</li></ol></li><li>d</li></ul><p>e</p>"),
+            "- a\n    1. b\n    2. c\n- d\n\ne"
+        );
+    }
+
+    #[test]
+    fn test_table() {
+        assert_eq!(
+            html_to_doc(
+                "<table><tr><th>id</th><th>name</th></tr><tr><td>1</td><td>a|b</td></tr></table>"
+            ),
+            "| id | name |\n| --- | --- |\n| 1 | a\\|b |"
+        );
+    }
+}
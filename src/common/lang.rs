@@ -0,0 +1,47 @@
+use std::path::PathBuf;
+
+use clap::ValueEnum;
+
+/// The languages problems can be pulled in.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Lang {
+    #[default]
+    Rust,
+    Python3,
+    Cpp,
+}
+
+impl Lang {
+    /// The language's `value` in LeetCode's code definitions.
+    pub fn slug(&self) -> &'static str {
+        match self {
+            Lang::Rust => "rust",
+            Lang::Python3 => "python3",
+            Lang::Cpp => "cpp",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Lang::Rust => "rs",
+            Lang::Python3 => "py",
+            Lang::Cpp => "cpp",
+        }
+    }
+
+    /// Where new problems are written. Rust problems are modules of this crate, the other
+    /// languages get a directory of their own at the root of the repository.
+    pub fn problem_dir(&self) -> PathBuf {
+        match self {
+            Lang::Rust => PathBuf::from("./src/problem"),
+            lang => PathBuf::from(format!("./{}/problem", lang.slug())),
+        }
+    }
+
+    pub fn solution_dir(&self) -> PathBuf {
+        match self {
+            Lang::Rust => PathBuf::from("./src/solution"),
+            lang => PathBuf::from(format!("./{}/solution", lang.slug())),
+        }
+    }
+}
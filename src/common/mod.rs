@@ -1,7 +1,15 @@
+pub mod auth;
+pub mod bench;
+pub mod cache;
 pub mod deal;
 pub mod fetch;
+pub mod html;
+pub mod lang;
+pub mod modules;
 pub mod problem;
 
+const CACHE_DIR: &str = "./.quip";
+const LEETCODE_URL: &str = "https://leetcode.com";
 const PROBLEMS_URL: &str = "https://leetcode.com/api/problems/algorithms/";
 const GRAPHQL_URL: &str = "https://leetcode.com/graphql";
 const QUESTION_QUERY_STRING: &str = r#"
@@ -11,7 +19,39 @@ query questionData($titleSlug: String!) {
         stats
         codeDefinition
         sampleTestCase
+        exampleTestcases
         metaData
+        topicTags {
+            name
+            slug
+        }
     }
 }"#;
 const QUESTION_QUERY_OPERATION: &str = "questionData";
+const DAILY_QUERY_STRING: &str = r#"
+query questionOfToday {
+    activeDailyCodingChallengeQuestion {
+        question {
+            frontendQuestionId: questionFrontendId
+        }
+    }
+}"#;
+const DAILY_QUERY_OPERATION: &str = "questionOfToday";
+const TAGGED_QUERY_STRING: &str = r#"
+query problemsetQuestionList($categorySlug: String, $limit: Int, $skip: Int, $filters: QuestionListFilterInput) {
+    problemsetQuestionList: questionList(categorySlug: $categorySlug, limit: $limit, skip: $skip, filters: $filters) {
+        questions: data {
+            frontendQuestionId: questionFrontendId
+        }
+    }
+}"#;
+const TAGGED_QUERY_OPERATION: &str = "problemsetQuestionList";
+const TAGGED_QUERY_LIMIT: u32 = 5000;
+const USER_STATUS_QUERY_STRING: &str = r#"
+query globalData {
+    userStatus {
+        isSignedIn
+        username
+    }
+}"#;
+const USER_STATUS_QUERY_OPERATION: &str = "globalData";
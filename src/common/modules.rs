@@ -0,0 +1,251 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Context};
+use syn::{Attribute, Item, ItemMod};
+
+// The contents of a mod.rs: its inner attributes and its module declarations keyed by name.
+// Keeping the declarations in a BTreeMap means the file is always written back sorted by problem
+// id. Declarations keep their own attributes and visibility.
+#[derive(Default)]
+struct Modules {
+    attrs: Vec<Attribute>,
+    decls: BTreeMap<String, ItemMod>,
+}
+
+fn parse(content: &str) -> anyhow::Result<syn::File> {
+    syn::parse_file(content).map_err(|e| anyhow!("failed to parse: {}", e))
+}
+
+// Parse a mod.rs we're about to rewrite, which may only hold module declarations so that nothing
+// is lost when it's written back.
+fn parse_modules(content: &str) -> anyhow::Result<Modules> {
+    let file = parse(content)?;
+
+    let mut modules = Modules {
+        attrs: file.attrs,
+        decls: BTreeMap::new(),
+    };
+    for item in file.items {
+        match item {
+            Item::Mod(m) if m.content.is_none() => {
+                modules.decls.insert(m.ident.to_string(), m);
+            }
+            _ => bail!("contains more than module declarations, refusing to rewrite it"),
+        }
+    }
+    Ok(modules)
+}
+
+fn render_modules(modules: Modules) -> String {
+    let file = syn::File {
+        shebang: None,
+        attrs: modules.attrs,
+        items: modules.decls.into_values().map(Item::Mod).collect(),
+    };
+    prettyplease::unparse(&file)
+}
+
+fn module_decl(name: &str) -> anyhow::Result<ItemMod> {
+    syn::parse_str(&format!("mod {};", name))
+        .with_context(|| format!("{} is not a valid module name", name))
+}
+
+// The modules declared in `content`, sorted by name. Anything else in the file is skipped.
+fn declared_modules(content: &str) -> anyhow::Result<Vec<String>> {
+    let mut names = parse(content)?
+        .items
+        .into_iter()
+        .filter_map(|item| match item {
+            Item::Mod(m) if m.content.is_none() => Some(m.ident.to_string()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    names.sort();
+    Ok(names)
+}
+
+// `content` with `name` declared. Unchanged if it's already declared.
+fn with_module(content: &str, name: &str) -> anyhow::Result<String> {
+    let mut modules = parse_modules(content)?;
+    if modules.decls.contains_key(name) {
+        return Ok(content.to_owned());
+    }
+    modules.decls.insert(name.to_owned(), module_decl(name)?);
+    Ok(render_modules(modules))
+}
+
+// `content` without the declaration of `name`. Unchanged if it isn't declared.
+fn without_module(content: &str, name: &str) -> anyhow::Result<String> {
+    let mut modules = parse_modules(content)?;
+    if modules.decls.remove(name).is_none() {
+        return Ok(content.to_owned());
+    }
+    Ok(render_modules(modules))
+}
+
+// A mod.rs declaring exactly `names`, keeping what `content` (if there's a mod.rs yet) had for
+// each of them.
+fn regenerated(content: Option<&str>, names: Vec<String>) -> anyhow::Result<String> {
+    let mut modules = match content {
+        Some(content) => parse_modules(content)?,
+        None => Modules::default(),
+    };
+
+    let mut decls = BTreeMap::new();
+    for name in names {
+        let decl = match modules.decls.remove(&name) {
+            Some(decl) => decl,
+            None => module_decl(&name)?,
+        };
+        decls.insert(name, decl);
+    }
+    modules.decls = decls;
+    Ok(render_modules(modules))
+}
+
+fn in_file(path: &Path, e: anyhow::Error) -> anyhow::Error {
+    anyhow!("{}: {}", path.display(), e)
+}
+
+fn read_mod_file(path: &Path) -> anyhow::Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+}
+
+fn update_mod_file(
+    dir: &Path,
+    update: impl FnOnce(&str) -> anyhow::Result<String>,
+) -> anyhow::Result<()> {
+    let path = dir.join("mod.rs");
+    let content = read_mod_file(&path)?;
+    let updated = update(&content).map_err(|e| in_file(&path, e))?;
+    if updated != content {
+        fs::write(&path, updated)?;
+    }
+    Ok(())
+}
+
+/// List the modules declared in `dir/mod.rs`, sorted by name. Other items in the file are
+/// ignored.
+pub fn list_modules(dir: &Path) -> anyhow::Result<Vec<String>> {
+    let path = dir.join("mod.rs");
+    declared_modules(&read_mod_file(&path)?).map_err(|e| in_file(&path, e))
+}
+
+/// Declare `name` in `dir/mod.rs`. Does nothing if it's already declared.
+pub fn add_module(dir: &Path, name: &str) -> anyhow::Result<()> {
+    update_mod_file(dir, |content| with_module(content, name))
+}
+
+/// Remove the declaration of `name` from `dir/mod.rs`, if any.
+pub fn remove_module(dir: &Path, name: &str) -> anyhow::Result<()> {
+    update_mod_file(dir, |content| without_module(content, name))
+}
+
+/// Rewrite `dir/mod.rs` to declare exactly the `.rs` files found in `dir`, keeping the
+/// attributes and visibility of modules that were already declared.
+pub fn regenerate_modules(dir: &Path) -> anyhow::Result<()> {
+    let path = dir.join("mod.rs");
+    let content = match path.exists() {
+        true => Some(read_mod_file(&path)?),
+        false => None,
+    };
+
+    let mut names = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|ext| ext != "rs") {
+            continue;
+        }
+        let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+        if name != "mod" {
+            names.push(name);
+        }
+    }
+
+    let updated = regenerated(content.as_deref(), names).map_err(|e| in_file(&path, e))?;
+    fs::write(&path, updated)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_module_sorted() {
+        let content = with_module("mod p0002_b;\n", "p0001_a").unwrap();
+        assert_eq!(content, "mod p0001_a;\nmod p0002_b;\n");
+        let content = with_module(&content, "p0003_c").unwrap();
+        assert_eq!(content, "mod p0001_a;\nmod p0002_b;\nmod p0003_c;\n");
+    }
+
+    #[test]
+    fn test_add_module_idempotent() {
+        let content = "mod p0001_a;\n";
+        assert_eq!(with_module(content, "p0001_a").unwrap(), content);
+        assert_eq!(with_module("", "p0001_a").unwrap(), content);
+    }
+
+    #[test]
+    fn test_keeps_visibility_and_attributes() {
+        let content = "#![allow(dead_code)]\n#[cfg(test)] mod p0002_b;\npub mod p0003_c;\n";
+        assert_eq!(
+            with_module(content, "p0001_a").unwrap(),
+            "#![allow(dead_code)]\nmod p0001_a;\n#[cfg(test)]\nmod p0002_b;\npub mod p0003_c;\n"
+        );
+        assert_eq!(
+            without_module(content, "p0003_c").unwrap(),
+            "#![allow(dead_code)]\n#[cfg(test)]\nmod p0002_b;\n"
+        );
+    }
+
+    #[test]
+    fn test_remove_module() {
+        assert_eq!(without_module("mod p0001_a;\n", "p0001_a").unwrap(), "");
+        assert_eq!(
+            without_module("mod p0001_a;\n", "p0002_b").unwrap(),
+            "mod p0001_a;\n"
+        );
+    }
+
+    #[test]
+    fn test_regenerated() {
+        let names = vec!["p0003_c".to_owned(), "p0001_a".to_owned()];
+        assert_eq!(
+            regenerated(Some("pub mod p0001_a;\nmod p0002_b;\n"), names.clone()).unwrap(),
+            "pub mod p0001_a;\nmod p0003_c;\n"
+        );
+        assert_eq!(
+            regenerated(None, names).unwrap(),
+            "mod p0001_a;\nmod p0003_c;\n"
+        );
+    }
+
+    #[test]
+    fn test_refuses_unmanaged_files() {
+        let content = "mod p0001_a;\npub use p0001_a::Solution;\n";
+        for result in [
+            with_module(content, "p0002_b"),
+            without_module(content, "p0001_a"),
+            regenerated(Some(content), vec![]),
+        ] {
+            assert!(result
+                .unwrap_err()
+                .to_string()
+                .contains("refusing to rewrite"));
+        }
+        assert!(with_module("mod tests {}\n", "p0001_a").is_err());
+    }
+
+    #[test]
+    fn test_declared_modules_skips_other_items() {
+        let content = "pub use p0002_b::Solution;\nmod p0002_b;\n#[cfg(test)]\nmod tests {}\npub mod p0001_a;\n";
+        assert_eq!(
+            declared_modules(content).unwrap(),
+            vec!["p0001_a", "p0002_b"]
+        );
+        assert!(declared_modules("mod").is_err());
+    }
+}
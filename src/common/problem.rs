@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
-use super::{QUESTION_QUERY_OPERATION, QUESTION_QUERY_STRING};
+use super::{
+    DAILY_QUERY_OPERATION, DAILY_QUERY_STRING, QUESTION_QUERY_OPERATION, QUESTION_QUERY_STRING,
+    TAGGED_QUERY_LIMIT, TAGGED_QUERY_OPERATION, TAGGED_QUERY_STRING, USER_STATUS_QUERY_OPERATION,
+    USER_STATUS_QUERY_STRING,
+};
 
 use std::fmt::{Display, Error, Formatter};
 
@@ -14,9 +18,14 @@ pub struct Problem {
     pub code_definition: Vec<CodeDefinition>,
     #[serde(rename = "sampleTestCase")]
     pub sample_test_case: String,
+    /// The inputs of every example, in the same format as `sample_test_case`, which only has
+    /// the first one.
+    #[serde(rename = "exampleTestcases")]
+    pub example_test_cases: String,
     pub difficulty: String,
     pub question_id: u32,
-    pub return_type: String,
+    pub meta_data: MetaData,
+    pub topic_tags: Vec<TopicTag>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -27,6 +36,27 @@ pub struct CodeDefinition {
     pub default_code: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MetaData {
+    #[serde(default)]
+    pub params: Vec<Param>,
+    #[serde(rename = "return")]
+    pub return_value: Option<ReturnValue>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Param {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub param_type: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReturnValue {
+    #[serde(rename = "type")]
+    pub return_type: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Query {
     #[serde(rename = "operationName")]
@@ -43,6 +73,35 @@ impl Query {
             query: QUESTION_QUERY_STRING.to_owned(),
         }
     }
+
+    pub fn daily_query() -> Query {
+        Query {
+            operation_name: DAILY_QUERY_OPERATION.to_owned(),
+            variables: json!({}),
+            query: DAILY_QUERY_STRING.to_owned(),
+        }
+    }
+
+    pub fn tagged_query(tag: &str) -> Query {
+        Query {
+            operation_name: TAGGED_QUERY_OPERATION.to_owned(),
+            variables: json!({
+                "categorySlug": "",
+                "limit": TAGGED_QUERY_LIMIT,
+                "skip": 0,
+                "filters": { "tags": [tag] },
+            }),
+            query: TAGGED_QUERY_STRING.to_owned(),
+        }
+    }
+
+    pub fn user_status_query() -> Query {
+        Query {
+            operation_name: USER_STATUS_QUERY_OPERATION.to_owned(),
+            variables: json!({}),
+            query: USER_STATUS_QUERY_STRING.to_owned(),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -63,8 +122,75 @@ pub struct Question {
     pub code_definition: String,
     #[serde(rename = "sampleTestCase")]
     pub sample_test_case: String,
+    // Missing from statements cached before it was queried.
+    #[serde(rename = "exampleTestcases", default)]
+    pub example_testcases: String,
     #[serde(rename = "metaData")]
     pub meta_data: String,
+    #[serde(rename = "topicTags", default)]
+    pub topic_tags: Vec<TopicTag>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TopicTag {
+    pub name: String,
+    pub slug: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RawDailyProblem {
+    pub data: DailyData,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DailyData {
+    #[serde(rename = "activeDailyCodingChallengeQuestion")]
+    pub active_daily_coding_challenge_question: DailyQuestion,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DailyQuestion {
+    pub question: QuestionId,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RawTaggedProblems {
+    pub data: TaggedData,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaggedData {
+    #[serde(rename = "problemsetQuestionList")]
+    pub problemset_question_list: TaggedQuestions,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaggedQuestions {
+    pub questions: Vec<QuestionId>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QuestionId {
+    #[serde(rename = "frontendQuestionId")]
+    pub frontend_question_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RawUserStatus {
+    pub data: UserStatusData,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserStatusData {
+    #[serde(rename = "userStatus")]
+    pub user_status: UserStatus,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserStatus {
+    #[serde(rename = "isSignedIn")]
+    pub is_signed_in: bool,
+    pub username: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -124,3 +250,32 @@ impl Display for Difficulty {
         }
     }
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Submission {
+    pub lang: String,
+    pub question_id: String,
+    pub typed_code: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubmissionId {
+    pub submission_id: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubmissionCheck {
+    pub state: String,
+    pub status_msg: Option<String>,
+    pub status_runtime: Option<String>,
+    pub status_memory: Option<String>,
+    pub runtime_percentile: Option<f64>,
+    pub memory_percentile: Option<f64>,
+    pub total_correct: Option<u32>,
+    pub total_testcases: Option<u32>,
+    pub compile_error: Option<String>,
+    pub runtime_error: Option<String>,
+    pub last_testcase: Option<String>,
+    pub expected_output: Option<String>,
+    pub code_output: Option<String>,
+}
@@ -8,8 +8,16 @@ pub mod solution;
 pub mod util;
 
 pub mod prelude {
+    pub use crate::commands::bench::BenchCommand;
     pub use crate::commands::init::InitCommand;
+    pub use crate::commands::login::LoginCommand;
+    pub use crate::commands::move_problem::MoveCommand;
     pub use crate::commands::pull::PullCommand;
+    pub use crate::commands::regen::RegenCommand;
+    pub use crate::commands::stats::StatsCommand;
+    pub use crate::commands::submit::SubmitCommand;
+    pub use crate::commands::sync::SyncCommand;
+    pub use crate::commands::test::TestCommand;
     pub use crate::consts::CODE_TITLE_TEXT;
 }
 
@@ -19,6 +19,30 @@ enum Commands {
 
     /// Pull a problem from LeetCode
     Pull(PullCommand),
+
+    /// Submit a solution to LeetCode
+    Submit(SubmitCommand),
+
+    /// Run the tests of a pulled problem
+    Test(TestCommand),
+
+    /// Cache the problem list (and optionally statements) for offline use
+    Sync(SyncCommand),
+
+    /// Move a solved problem into solution/
+    Move(MoveCommand),
+
+    /// Regenerate module declarations from the files on disk
+    Regen(RegenCommand),
+
+    /// Benchmark a pulled problem on its sample inputs
+    Bench(BenchCommand),
+
+    /// Show progress statistics
+    Stats(StatsCommand),
+
+    /// Store your LeetCode session
+    Login(LoginCommand),
 }
 
 #[tokio::main]
@@ -35,6 +59,30 @@ async fn main() {
         Commands::Pull(pull) => {
             pull.run().await;
         }
+        Commands::Submit(submit) => {
+            submit.run().await;
+        }
+        Commands::Test(test) => {
+            test.run();
+        }
+        Commands::Sync(sync) => {
+            sync.run().await;
+        }
+        Commands::Move(mv) => {
+            mv.run();
+        }
+        Commands::Regen(regen) => {
+            regen.run();
+        }
+        Commands::Bench(bench) => {
+            bench.run().await;
+        }
+        Commands::Stats(stats) => {
+            stats.run().await;
+        }
+        Commands::Login(login) => {
+            login.run().await;
+        }
     }
     outro("Good luck on your journey.\n").expect("Could not print outro");
 }
@@ -55,6 +55,36 @@ pub fn to_graph(vec: Vec<(i32, Vec<i32>)>) -> Vec<Rc<RefCell<GraphNode>>> {
     nodes
 }
 
+/// Parse LeetCode's adjacency list notation, e.g. `"[[2,4],[1,3],[2,4],[1,3]]"`, where the
+/// node at index `i` has value `i + 1`.
+pub fn parse_graph(s: &str) -> Vec<Rc<RefCell<GraphNode>>> {
+    let adjacency: Vec<Vec<i32>> = serde_json::from_str(s).expect("Invalid graph notation");
+    to_graph(
+        adjacency
+            .into_iter()
+            .enumerate()
+            .map(|(i, neighbors)| (i as i32 + 1, neighbors))
+            .collect(),
+    )
+}
+
+/// Render a graph with one line per node listing its neighbors, e.g. `1 -> [2, 4]`.
+pub fn graph_to_string(nodes: &[Rc<RefCell<GraphNode>>]) -> String {
+    nodes
+        .iter()
+        .map(|node| {
+            let node = node.borrow();
+            let neighbors = node
+                .neighbors
+                .iter()
+                .map(|n| n.borrow().val.to_string())
+                .collect::<Vec<_>>();
+            format!("{} -> [{}]", node.val, neighbors.join(", "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 #[macro_export]
 macro_rules! graph {
     ($($val:expr => [$($neighbors:expr),*]),*) => {
@@ -64,3 +94,27 @@ macro_rules! graph {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_graph() {
+        let nodes = parse_graph("[[2,4],[1,3],[2,4],[1,3]]");
+        assert_eq!(
+            graph_to_string(&nodes),
+            "1 -> [2, 4]\n2 -> [1, 3]\n3 -> [2, 4]\n4 -> [1, 3]"
+        );
+        assert_eq!(
+            graph_to_string(&nodes),
+            graph_to_string(&graph!(1 => [2, 4], 2 => [1, 3], 3 => [2, 4], 4 => [1, 3]))
+        );
+    }
+
+    #[test]
+    fn test_graph_to_string() {
+        assert_eq!(graph_to_string(&parse_graph("[[]]")), "1 -> []");
+        assert_eq!(graph_to_string(&parse_graph("[]")), "");
+    }
+}
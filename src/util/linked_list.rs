@@ -22,8 +22,46 @@ pub fn to_list(vec: Vec<i32>) -> Option<Box<ListNode>> {
     current
 }
 
+/// Parse LeetCode's bracket notation, e.g. `"[1,2,3]"`, into a list.
+pub fn parse_list(s: &str) -> Option<Box<ListNode>> {
+    to_list(serde_json::from_str(s).expect("Invalid list notation"))
+}
+
+/// Render a list as `1 -> 2 -> 3 -> None`.
+pub fn list_to_string(head: &Option<Box<ListNode>>) -> String {
+    let mut out = String::new();
+    let mut current = head;
+    while let Some(node) = current {
+        out.push_str(&format!("{} -> ", node.val));
+        current = &node.next;
+    }
+    out.push_str("None");
+    out
+}
+
 #[macro_export]
 macro_rules! linked {
     ($($e:expr),*) => {to_list(vec![$($e.to_owned()), *])};
     ($($e:expr,)*) => {to_list(vec![$($e.to_owned()), *])};
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_list() {
+        assert_eq!(parse_list("[1,2,3]"), linked![1, 2, 3]);
+        assert_eq!(parse_list("[]"), None);
+    }
+
+    #[test]
+    fn test_list_to_string() {
+        assert_eq!(
+            list_to_string(&parse_list("[1,2,3]")),
+            "1 -> 2 -> 3 -> None"
+        );
+        assert_eq!(list_to_string(&parse_list("[1]")), "1 -> None");
+        assert_eq!(list_to_string(&None), "None");
+    }
+}
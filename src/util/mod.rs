@@ -10,3 +10,7 @@ pub mod point;
 pub mod graph;
 #[macro_use]
 pub mod nested_integer;
+#[macro_use]
+pub mod nary;
+#[cfg(test)]
+pub mod testing;
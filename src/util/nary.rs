@@ -0,0 +1,88 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Node {
+    pub val: i32,
+    pub children: Vec<Rc<RefCell<Node>>>,
+}
+
+impl Node {
+    #[inline]
+    pub fn new(val: i32) -> Self {
+        Node {
+            val,
+            children: Vec::new(),
+        }
+    }
+}
+
+/// Build an N-ary tree from LeetCode's level-order notation, where each group of children is
+/// terminated by a `None`, e.g. `[1, null, 3, 2, 4, null, 5, 6]`.
+pub fn to_nary(vec: Vec<Option<i32>>) -> Option<Rc<RefCell<Node>>> {
+    let root = Rc::new(RefCell::new(Node::new((*vec.first()?)?)));
+    let mut queue = VecDeque::new();
+    queue.push_back(root.clone());
+
+    let mut parent = None;
+    for v in &vec[1..] {
+        match v {
+            None => parent = queue.pop_front(),
+            Some(v) => {
+                let child = Rc::new(RefCell::new(Node::new(*v)));
+                parent
+                    .as_ref()
+                    .expect("Invalid N-ary tree notation")
+                    .borrow_mut()
+                    .children
+                    .push(child.clone());
+                queue.push_back(child);
+            }
+        }
+    }
+    Some(root)
+}
+
+/// Parse LeetCode's bracket notation, e.g. `"[1,null,3,2,4,null,5,6]"`, into an N-ary tree.
+pub fn parse_nary(s: &str) -> Option<Rc<RefCell<Node>>> {
+    to_nary(serde_json::from_str(s).expect("Invalid N-ary tree notation"))
+}
+
+/// Build an N-ary tree node by node: `nary!(1, [nary!(3, [nary!(5), nary!(6)]), nary!(2)])`.
+#[macro_export]
+macro_rules! nary {
+    ($val:expr) => {
+        ::std::rc::Rc::new(::std::cell::RefCell::new($crate::util::nary::Node::new($val)))
+    };
+    ($val:expr, [$($child:expr),* $(,)?]) => {
+        {
+            let node = $crate::nary!($val);
+            node.borrow_mut().children = vec![$($child),*];
+            node
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_nary() {
+        let root = to_nary(vec![
+            Some(1),
+            None,
+            Some(3),
+            Some(2),
+            Some(4),
+            None,
+            Some(5),
+            Some(6),
+        ]);
+        let expected = nary!(1, [nary!(3, [nary!(5), nary!(6)]), nary!(2), nary!(4)]);
+        assert_eq!(root, Some(expected));
+        assert_eq!(parse_nary("[1,null,3,2,4,null,5,6]"), root);
+        assert_eq!(parse_nary("[]"), None);
+    }
+}
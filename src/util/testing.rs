@@ -1 +1,157 @@
+//! Proptest strategies for the structures in `util`, for property tests of solutions:
+//!
+//! ```ignore
+//! use proptest::prelude::*;
+//! use crate::util::testing;
+//!
+//! proptest! {
+//!     #[test]
+//!     fn test_max_depth(root in testing::tree(0..=100, 0..50)) {
+//!         prop_assert!(Solution::max_depth(root) <= 50);
+//!     }
+//! }
+//! ```
 
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use proptest::collection::{vec, SizeRange};
+use proptest::prelude::*;
+use proptest::sample::Index;
+
+use crate::util::linked_list::{to_list, ListNode};
+use crate::util::nary::Node;
+use crate::util::tree::TreeNode;
+
+/// Lists of `size` nodes with values from `values`.
+pub fn list(
+    values: impl Strategy<Value = i32>,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = Option<Box<ListNode>>> {
+    vec(values, size).prop_map(to_list)
+}
+
+/// Binary trees of `size` nodes with values from `values` and a random shape.
+pub fn tree(
+    values: impl Strategy<Value = i32>,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = Option<Rc<RefCell<TreeNode>>>> {
+    vec((values, any::<Index>()), size).prop_map(|nodes| {
+        let mut nodes = nodes.into_iter();
+        let (val, _) = nodes.next()?;
+        let root = Rc::new(RefCell::new(TreeNode::new(val)));
+
+        // Each node takes a random free child slot among the nodes placed before it.
+        let mut slots = vec![(root.clone(), true), (root.clone(), false)];
+        for (val, index) in nodes {
+            let (parent, left) = slots.swap_remove(index.index(slots.len()));
+            let node = Rc::new(RefCell::new(TreeNode::new(val)));
+            match left {
+                true => parent.borrow_mut().left = Some(node.clone()),
+                false => parent.borrow_mut().right = Some(node.clone()),
+            }
+            slots.push((node.clone(), true));
+            slots.push((node, false));
+        }
+        Some(root)
+    })
+}
+
+/// N-ary trees of `size` nodes with values from `values` and a random shape.
+pub fn nary(
+    values: impl Strategy<Value = i32>,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = Option<Rc<RefCell<Node>>>> {
+    vec((values, any::<Index>()), size).prop_map(|nodes| {
+        let mut nodes = nodes.into_iter();
+        let (val, _) = nodes.next()?;
+        let root = Rc::new(RefCell::new(Node::new(val)));
+
+        // Each node becomes a child of a random node placed before it.
+        let mut placed = vec![root.clone()];
+        for (val, index) in nodes {
+            let node = Rc::new(RefCell::new(Node::new(val)));
+            placed[index.index(placed.len())]
+                .borrow_mut()
+                .children
+                .push(node.clone());
+            placed.push(node);
+        }
+        Some(root)
+    })
+}
+
+/// Undirected graphs of `size` nodes valued `1..=n`, as the adjacency list `to_graph` takes.
+/// The nodes themselves aren't generated because printing a failing case would recurse through
+/// the cycles forever.
+pub fn graph(size: impl Into<SizeRange>) -> impl Strategy<Value = Vec<(i32, Vec<i32>)>> {
+    let size = size.into();
+    (size.start()..=size.end_incl())
+        .prop_flat_map(|n| (Just(n), vec(any::<bool>(), n * n.saturating_sub(1) / 2)))
+        .prop_map(|(n, edges)| {
+            let mut adjacency = (1..=n as i32).map(|val| (val, vec![])).collect::<Vec<_>>();
+            let mut edges = edges.into_iter();
+            for i in 0..n {
+                for j in i + 1..n {
+                    if edges.next().unwrap() {
+                        adjacency[i].1.push(j as i32 + 1);
+                        adjacency[j].1.push(i as i32 + 1);
+                    }
+                }
+            }
+            adjacency
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree_size(node: &Option<Rc<RefCell<TreeNode>>>) -> usize {
+        node.as_ref().map_or(0, |node| {
+            let node = node.borrow();
+            1 + tree_size(&node.left) + tree_size(&node.right)
+        })
+    }
+
+    fn nary_size(node: &Rc<RefCell<Node>>) -> usize {
+        1 + node.borrow().children.iter().map(nary_size).sum::<usize>()
+    }
+
+    fn list_size(mut node: &Option<Box<ListNode>>) -> usize {
+        let mut size = 0;
+        while let Some(n) = node {
+            size += 1;
+            node = &n.next;
+        }
+        size
+    }
+
+    proptest! {
+        #[test]
+        fn test_list_size((n, head) in (0..50usize).prop_flat_map(|n| (Just(n), list(any::<i32>(), n)))) {
+            prop_assert_eq!(list_size(&head), n);
+        }
+
+        #[test]
+        fn test_tree_size((n, root) in (0..50usize).prop_flat_map(|n| (Just(n), tree(any::<i32>(), n)))) {
+            prop_assert_eq!(tree_size(&root), n);
+        }
+
+        #[test]
+        fn test_nary_size((n, root) in (0..50usize).prop_flat_map(|n| (Just(n), nary(any::<i32>(), n)))) {
+            prop_assert_eq!(root.as_ref().map_or(0, nary_size), n);
+        }
+
+        #[test]
+        fn test_graph_symmetric((n, adjacency) in (0..20usize).prop_flat_map(|n| (Just(n), graph(n)))) {
+            prop_assert_eq!(adjacency.len(), n);
+            for (val, neighbors) in &adjacency {
+                for neighbor in neighbors {
+                    prop_assert_ne!(neighbor, val);
+                    prop_assert!(adjacency[*neighbor as usize - 1].1.contains(val));
+                }
+            }
+        }
+    }
+}
@@ -41,6 +41,55 @@ pub fn to_tree(vec: Vec<Option<i32>>) -> Option<Rc<RefCell<TreeNode>>> {
     head
 }
 
+/// Parse LeetCode's level-order bracket notation, e.g. `"[1,2,null,3]"`, into a tree.
+pub fn parse_tree(s: &str) -> Option<Rc<RefCell<TreeNode>>> {
+    let vec: Vec<Option<i32>> = serde_json::from_str(s).expect("Invalid tree notation");
+    match vec.first() {
+        Some(Some(_)) => to_tree(vec),
+        _ => None,
+    }
+}
+
+/// Render a tree with one node per line, children below their parent:
+///
+/// ```text
+/// 1
+/// ├── 2
+/// │   ├── None
+/// │   └── 4
+/// └── 3
+/// ```
+///
+/// A missing child is only shown when its sibling exists.
+pub fn tree_to_ascii(root: &Option<Rc<RefCell<TreeNode>>>) -> String {
+    fn walk(node: &Option<Rc<RefCell<TreeNode>>>, prefix: &str, last: bool, out: &mut String) {
+        let branch = if last { "└── " } else { "├── " };
+        let Some(node) = node else {
+            out.push_str(&format!("{}{}None\n", prefix, branch));
+            return;
+        };
+        let node = node.borrow();
+        out.push_str(&format!("{}{}{}\n", prefix, branch, node.val));
+
+        if node.left.is_some() || node.right.is_some() {
+            let prefix = format!("{}{}", prefix, if last { "    " } else { "│   " });
+            walk(&node.left, &prefix, false, out);
+            walk(&node.right, &prefix, true, out);
+        }
+    }
+
+    let Some(node) = root else {
+        return "None".to_owned();
+    };
+    let node = node.borrow();
+    let mut out = format!("{}\n", node.val);
+    if node.left.is_some() || node.right.is_some() {
+        walk(&node.left, "", false, &mut out);
+        walk(&node.right, "", true, &mut out);
+    }
+    out.trim_end().to_owned()
+}
+
 #[macro_export]
 macro_rules! tree {
     () => {
@@ -55,3 +104,33 @@ macro_rules! tree {
     };
     ($($e:expr,)*) => {(tree![$($e),*])};
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tree() {
+        assert_eq!(parse_tree("[1,2,null,3]"), tree![1, 2, null, 3]);
+        assert_eq!(parse_tree("[]"), None);
+        assert_eq!(parse_tree("[null]"), None);
+    }
+
+    #[test]
+    fn test_tree_to_ascii() {
+        assert_eq!(tree_to_ascii(&None), "None");
+        assert_eq!(tree_to_ascii(&parse_tree("[1]")), "1");
+        assert_eq!(
+            tree_to_ascii(&parse_tree("[1,null,2]")),
+            "1\n├── None\n└── 2"
+        );
+        assert_eq!(
+            tree_to_ascii(&parse_tree("[1,2,null,3]")),
+            "1\n├── 2\n│   ├── 3\n│   └── None\n└── None"
+        );
+        assert_eq!(
+            tree_to_ascii(&parse_tree("[1,2,3,null,4,5]")),
+            "1\n├── 2\n│   ├── None\n│   └── 4\n└── 3\n    ├── 5\n    └── None"
+        );
+    }
+}
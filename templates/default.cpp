@@ -0,0 +1,18 @@
+/**
+ * [__PROBLEM_ID__] __PROBLEM_TITLE__
+ *
+ * __PROBLEM_DESC__
+ */
+
+// problem: __PROBLEM_LINK__
+// discuss: __DISCUSS_LINK__
+
+#include <cassert>
+#include <iostream>__EXTRA_USE__
+using namespace std;
+
+__PROBLEM_DEFAULT_CODE__
+
+int main() {__PROBLEM_TEST_CASES__
+    cout << "test___PROBLEM_ID__ passed" << endl;
+}
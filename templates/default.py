@@ -0,0 +1,25 @@
+r"""
+[__PROBLEM_ID__] __PROBLEM_TITLE__
+
+__PROBLEM_DESC__
+"""
+
+from __future__ import annotations
+
+# problem: __PROBLEM_LINK__
+# discuss: __DISCUSS_LINK__
+
+import unittest
+from typing import *
+
+
+__PROBLEM_DEFAULT_CODE__
+
+
+class Test__PROBLEM_ID__(unittest.TestCase):
+    def test___PROBLEM_ID__(self):
+        pass__PROBLEM_TEST_CASES__
+
+
+if __name__ == "__main__":
+    unittest.main()
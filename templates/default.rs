@@ -18,6 +18,6 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test___PROBLEM_ID__() {
+    fn test___PROBLEM_ID__() {__PROBLEM_TEST_CASES__
     }
 }
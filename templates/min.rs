@@ -0,0 +1,16 @@
+// [__PROBLEM_ID__] __PROBLEM_TITLE__ (__PROBLEM_DIFFICULTY__)
+// __PROBLEM_LINK__
+__EXTRA_USE__
+
+pub struct Solution {}
+
+__PROBLEM_DEFAULT_CODE__
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test___PROBLEM_ID__() {__PROBLEM_TEST_CASES__
+    }
+}